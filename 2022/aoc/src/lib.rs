@@ -8,12 +8,11 @@ pub mod nested_list;
 pub mod tree;
 
 use std::{
-    env::current_exe,
-    ffi::OsStr,
+    env::{self, current_exe},
     fs::File,
     io::{BufRead, BufReader, Lines},
     iter::Map,
-    path::{Component, Path, PathBuf},
+    path::{Path, PathBuf},
 };
 
 use num::Integer;
@@ -26,26 +25,40 @@ pub fn aoc_sample_input() -> PathBuf {
     aoc_input_core(true)
 }
 
+// Looks for the input in the directory set by the `AOC_INPUT_DIR` environment variable, and then
+// in the `input` directory of the workspace this crate was built in, panicking with every path
+// that was tried if it's in neither.
 fn aoc_input_core(sample: bool) -> PathBuf {
-    let path = current_exe().unwrap();
-    let mut name = path.file_name().unwrap().to_str().unwrap();
-    if let Some(index) = name.find('-') {
-        name = &name[..index];
-    }
-
-    let mut input: PathBuf = path
-        .components()
-        .take_while(|c| *c != Component::Normal(OsStr::new("target")))
-        .collect();
-
-    input.push("input");
+    let exe = current_exe().unwrap();
+    let mut file = PathBuf::new();
     if sample {
-        input.push("sample");
+        file.push("sample");
     }
 
-    input.push(name);
-    input.set_extension("txt");
-    input
+    // Test executables have a hash after a '-'.
+    let name = exe.file_stem().unwrap().to_str().unwrap();
+    file.push(name.split('-').next().unwrap());
+    file.set_extension("txt");
+
+    let mut dirs = Vec::new();
+    dirs.extend(env::var_os("AOC_INPUT_DIR").map(PathBuf::from));
+    dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name("input"));
+    let tried = dirs
+        .into_iter()
+        .map(|dir| dir.join(&file))
+        .collect::<Vec<_>>();
+
+    match tried.iter().find(|path| path.exists()) {
+        Some(path) => path.clone(),
+        None => panic!(
+            "input file not found; tried {}",
+            tried
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
 }
 
 pub type FileInput = Map<Lines<BufReader<File>>, fn(std::io::Result<String>) -> String>;
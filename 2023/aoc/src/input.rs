@@ -1,10 +1,9 @@
 use std::{
-    env::current_exe,
-    ffi::OsStr,
+    env::{self, current_exe},
     fs::File,
     io::{BufRead, BufReader, Lines},
     iter::Map,
-    path::{Component, Path, PathBuf},
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
@@ -23,26 +22,38 @@ impl AocInput {
         AocInput::get_path_core(Some(name), sample)
     }
 
+    // Gets the path of an existing file, or the path in the first directory if there isn't one.
     fn get_path_core(name: Option<&str>, sample: bool) -> PathBuf {
-        let path = current_exe().unwrap();
-        let mut name = name.unwrap_or_else(|| path.file_name().unwrap().to_str().unwrap());
-        if let Some(index) = name.find('-') {
-            name = &name[..index];
-        }
-
-        let mut input: PathBuf = path
-            .components()
-            .take_while(|c| *c != Component::Normal(OsStr::new("target")))
-            .collect();
+        Self::find_path(name, sample).unwrap_or_else(|tried| tried.into_iter().next().unwrap())
+    }
 
-        input.push("input");
+    // Looks for the file in the directory set by the `AOC_INPUT_DIR` environment variable, and
+    // then in the `input` directory of the workspace this crate was built in. If it's in neither,
+    // returns every path that was tried.
+    fn find_path(name: Option<&str>, sample: bool) -> Result<PathBuf, Vec<PathBuf>> {
+        let exe = current_exe().unwrap();
+        let name = name.unwrap_or_else(|| exe.file_stem().unwrap().to_str().unwrap());
+        let mut file = PathBuf::new();
         if sample {
-            input.push("sample");
+            file.push("sample");
         }
 
-        input.push(name);
-        input.set_extension("txt");
-        input
+        // Test executables have a hash after a '-'.
+        file.push(name.split('-').next().unwrap());
+        file.set_extension("txt");
+
+        let mut dirs = Vec::new();
+        dirs.extend(env::var_os("AOC_INPUT_DIR").map(PathBuf::from));
+        dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name("input"));
+        let tried = dirs
+            .into_iter()
+            .map(|dir| dir.join(&file))
+            .collect::<Vec<_>>();
+
+        match tried.iter().find(|path| path.exists()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(tried),
+        }
     }
 
     pub fn from_file(path: impl AsRef<Path>) -> Self {
@@ -54,11 +65,18 @@ impl AocInput {
     }
 
     pub fn from_input() -> Self {
-        Self::from_file(Self::get_path(false))
+        Self::from_found(false)
     }
 
     pub fn from_sample() -> Self {
-        Self::from_file(Self::get_path(true))
+        Self::from_found(true)
+    }
+
+    fn from_found(sample: bool) -> Self {
+        match Self::find_path(None, sample) {
+            Ok(path) => Self::from_file(path),
+            Err(tried) => panic!("input file not found; tried {}", tried_paths(&tried)),
+        }
     }
 
     pub fn single_line(mut self) -> String {
@@ -99,6 +117,14 @@ impl AocInput {
     }
 }
 
+fn tried_paths(tried: &[PathBuf]) -> String {
+    tried
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl Iterator for AocInput {
     type Item = String;
 
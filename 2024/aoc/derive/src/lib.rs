@@ -0,0 +1,279 @@
+//! Procedural macros for the `aoc` crate. Use them through the re-exports in `aoc`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Data, DeriveInput, Error, Expr, Fields, Ident, LitStr, Result, Token, Type,
+};
+
+/// Implements `FromStr` for a struct with named fields, using a format given by the
+/// `#[aoc(format = "...")]` attribute.
+///
+/// Each `{field}` in the format is parsed using the field's `FromStr` implementation. A field
+/// written as `{field:separator}` is a list, which is split on the separator and collected into
+/// the field's type, e.g. `{tunnels:, }` for a `Vec<String>` separated by `", "`. The rest of the
+/// format is matched as in `aoc::scan::Pattern`.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A placeholder in the format.
+struct Placeholder {
+    field: String,
+    separator: Option<String>,
+}
+
+fn expand(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input,
+            "AocParse can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input,
+            "AocParse requires a struct with named fields",
+        ));
+    };
+
+    let format = find_format(&input)?;
+    let (pattern, placeholders) =
+        parse_format(&format.value()).map_err(|message| Error::new(format.span(), message))?;
+
+    let mut field_values = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        let Some(index) = placeholders.iter().position(|p| p.field == name) else {
+            return Err(Error::new_spanned(
+                ident,
+                format!("field `{name}` is not in the format"),
+            ));
+        };
+
+        let value = match &placeholders[index].separator {
+            Some(separator) => quote! {
+                ::aoc::scan::parse_list_field(#name, captures[#index], #separator)?
+            },
+            None => quote! { ::aoc::scan::parse_field(#name, captures[#index])? },
+        };
+
+        field_values.push(quote! { #ident: #value });
+    }
+
+    if let Some(placeholder) = placeholders.iter().find(|p| {
+        !fields
+            .named
+            .iter()
+            .any(|f| f.ident.as_ref().unwrap() == &p.field)
+    }) {
+        return Err(Error::new(
+            format.span(),
+            format!("the struct has no field `{}`", placeholder.field),
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::aoc::scan::ScanError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                static PATTERN: ::std::sync::OnceLock<::aoc::scan::Pattern> =
+                    ::std::sync::OnceLock::new();
+
+                let captures = PATTERN
+                    .get_or_init(|| ::aoc::scan::Pattern::new(#pattern))
+                    .captures(s)?;
+
+                Ok(Self { #(#field_values),* })
+            }
+        }
+    })
+}
+
+fn find_format(input: &DeriveInput) -> Result<LitStr> {
+    let mut format = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("aoc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown aoc attribute"))
+            }
+        })?;
+    }
+
+    format.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "AocParse requires an #[aoc(format = \"...\")] attribute",
+        )
+    })
+}
+
+/// Replaces the named placeholders with `{}`, and returns them in order.
+fn parse_format(format: &str) -> std::result::Result<(String, Vec<Placeholder>), String> {
+    let mut pattern = String::new();
+    let mut placeholders = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find('{') {
+        pattern.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            return Err(format!("unterminated placeholder in {format:?}"));
+        };
+
+        let placeholder = &rest[start + 1..start + end];
+        let (field, separator) = match placeholder.split_once(':') {
+            Some((field, separator)) => (field, Some(separator.to_owned())),
+            None => (placeholder, None),
+        };
+
+        if field.is_empty() {
+            return Err(format!("placeholder without a field name in {format:?}"));
+        }
+
+        if separator.as_deref() == Some("") {
+            return Err(format!("list field `{field}` has an empty separator"));
+        }
+
+        placeholders.push(Placeholder {
+            field: field.to_owned(),
+            separator,
+        });
+
+        pattern.push_str("{}");
+        rest = &rest[start + end + 1..];
+    }
+
+    pattern.push_str(rest);
+    Ok((pattern, placeholders))
+}
+
+/// Generates a test for each sample of a day, comparing a part's answer with the expected value.
+///
+/// ```ignore
+/// aoc_tests!(Day4, part1: [("", 18), ("b", 4)], part2: [("", 9)]);
+/// ```
+///
+/// Each entry names a sample file by its suffix, as used by `AocInput::from_sample_named`, and
+/// generates a test called e.g. `part1_sample` or `part1_sample_b`.
+#[proc_macro]
+pub fn aoc_tests(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TestTable);
+    expand_tests(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct TestTable {
+    solution: Type,
+    parts: Vec<(Ident, Vec<(LitStr, Expr)>)>,
+}
+
+impl Parse for TestTable {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let solution = input.parse()?;
+        let mut parts = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let part = input.parse::<Ident>()?;
+            input.parse::<Token![:]>()?;
+            let content;
+            bracketed!(content in input);
+            let entries = Punctuated::<TestEntry, Token![,]>::parse_terminated(&content)?;
+            parts.push((
+                part,
+                entries
+                    .into_iter()
+                    .map(|entry| (entry.suffix, entry.expected))
+                    .collect(),
+            ));
+        }
+
+        Ok(Self { solution, parts })
+    }
+}
+
+struct TestEntry {
+    suffix: LitStr,
+    expected: Expr,
+}
+
+impl Parse for TestEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let suffix = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let expected = content.parse()?;
+        content.parse::<Option<Token![,]>>()?;
+        Ok(Self { suffix, expected })
+    }
+}
+
+fn expand_tests(input: TestTable) -> Result<proc_macro2::TokenStream> {
+    let solution = &input.solution;
+    let mut tests = Vec::new();
+    for (part, entries) in &input.parts {
+        if part != "part1" && part != "part2" {
+            return Err(Error::new_spanned(part, "expected part1 or part2"));
+        }
+
+        for (suffix, expected) in entries {
+            let value = suffix.value();
+            if !value
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+            {
+                return Err(Error::new_spanned(
+                    suffix,
+                    "the sample suffix can only contain letters, digits and underscores",
+                ));
+            }
+
+            let name = if value.is_empty() {
+                format_ident!("{part}_sample")
+            } else {
+                format_ident!("{part}_sample_{value}")
+            };
+
+            tests.push(quote! {
+                #[test]
+                fn #name() {
+                    let input = <#solution as ::aoc::solution::Solution>::parse(
+                        ::aoc::input::AocInput::from_sample_named(#suffix),
+                    );
+
+                    assert_eq!(
+                        (#expected).to_string(),
+                        <#solution as ::aoc::solution::Solution>::#part(&input).to_string()
+                    );
+                }
+            });
+        }
+    }
+
+    Ok(quote! { #(#tests)* })
+}
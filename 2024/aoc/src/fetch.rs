@@ -0,0 +1,611 @@
+//! Downloads puzzle inputs from, and submits answers to, the Advent of Code website.
+//!
+//! The session cookie is read from the `AOC_SESSION` environment variable, or from the file
+//! `aoc/session` in the user's configuration directory (e.g. `~/.config/aoc/session`). The time of
+//! the last request is saved in the same directory, so separate commands are throttled too.
+
+use std::{
+    env, fs,
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use thiserror::Error;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str = "github.com/SvenGroot/AdventOfCode by Sven Groot";
+pub const DEFAULT_MIN_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error("day {day} of {year} is not a valid puzzle")]
+    InvalidDay { year: u32, day: u32 },
+    #[error("day {day} of {year} is not unlocked yet")]
+    NotUnlocked { year: u32, day: u32 },
+    #[error("request failed: {0}")]
+    Http(#[from] Box<ureq::Error>),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// The website's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, but the website didn't say whether it was too high or too low.
+    Incorrect,
+    /// An answer was submitted too recently, so this one wasn't checked.
+    TooRecent,
+    /// The part was already solved, or part 1 hasn't been solved yet.
+    WrongLevel,
+    /// The response wasn't recognized; contains the text of the response.
+    Unknown(String),
+}
+
+/// The response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmitResponse {
+    pub verdict: Verdict,
+    /// How long to wait before submitting another answer, if the website said so.
+    pub wait: Option<Duration>,
+}
+
+/// A client for the Advent of Code website, which makes sure requests aren't sent too quickly.
+pub struct Client {
+    session: String,
+    base_url: String,
+    user_agent: String,
+    min_delay: Duration,
+    last_request: Option<SystemTime>,
+    last_request_path: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            session: session.into(),
+            base_url: DEFAULT_BASE_URL.into(),
+            user_agent: DEFAULT_USER_AGENT.into(),
+            min_delay: DEFAULT_MIN_DELAY,
+            last_request: None,
+            last_request_path: config_dir().map(|dir| dir.join("aoc/last_request")),
+        }
+    }
+
+    /// Creates a client using the session from the environment or the configuration file, if
+    /// there is one.
+    pub fn from_env() -> Option<Self> {
+        find_session().map(Self::new)
+    }
+
+    pub fn base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn user_agent(self, user_agent: impl Into<String>) -> Self {
+        Self {
+            user_agent: user_agent.into(),
+            ..self
+        }
+    }
+
+    /// Sets the minimum time between two requests.
+    pub fn min_delay(self, min_delay: Duration) -> Self {
+        Self { min_delay, ..self }
+    }
+
+    /// Sets the file used to share the time of the last request with other clients, or `None`
+    /// to only throttle the requests of this client.
+    pub fn last_request_path(self, last_request_path: Option<PathBuf>) -> Self {
+        Self {
+            last_request_path,
+            ..self
+        }
+    }
+
+    /// Downloads the input for a day to `path`, unless that file already exists. Returns `true` if
+    /// the input was downloaded.
+    pub fn fetch_input(&mut self, year: u32, day: u32, path: &Path) -> Result<bool, FetchError> {
+        if path.exists() {
+            return Ok(false);
+        }
+
+        check_unlocked(year, day)?;
+        let input = self
+            .get(&format!("{year}/day/{day}/input"))?
+            .into_string()?;
+
+        // Only create the file once the download succeeded, so a failure doesn't leave an empty
+        // file that looks like a cached input.
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        match fs::File::create_new(path) {
+            Ok(mut file) => file.write_all(input.as_bytes())?,
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => return Ok(false),
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(true)
+    }
+
+    /// Submits an answer for one part of a day's puzzle.
+    pub fn submit_answer(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<SubmitResponse, FetchError> {
+        check_unlocked(year, day)?;
+        let response = self
+            .request("POST", &format!("{year}/day/{day}/answer"))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(Box::new)?
+            .into_string()?;
+
+        Ok(parse_submit_response(&response))
+    }
+
+    fn get(&mut self, path: &str) -> Result<ureq::Response, FetchError> {
+        let response = self.request("GET", path).call().map_err(Box::new)?;
+        Ok(response)
+    }
+
+    fn request(&mut self, method: &str, path: &str) -> ureq::Request {
+        self.throttle();
+        ureq::request(method, &self.url(path))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url.trim_end_matches('/'))
+    }
+
+    fn throttle(&mut self) {
+        let saved = self
+            .last_request_path
+            .as_deref()
+            .and_then(read_last_request);
+        if let Some(last) = self.last_request.max(saved) {
+            // If the clock went back, the elapsed time is treated as zero.
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+
+            if elapsed < self.min_delay {
+                thread::sleep(self.min_delay - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        if let Some(path) = &self.last_request_path {
+            // Failing to save the time only means that another command may not wait long enough.
+            if let Err(e) = write_last_request(path, now) {
+                eprintln!("Warning: could not save the time of the last request: {e}");
+            }
+        }
+    }
+}
+
+/// Finds the session cookie in the `AOC_SESSION` environment variable, or the configuration file.
+pub fn find_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let session = fs::read_to_string(config_dir()?.join("aoc/session")).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_owned())
+}
+
+// The time is saved as nanoseconds since the Unix epoch.
+fn read_last_request(path: &Path) -> Option<SystemTime> {
+    let nanos = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+}
+
+fn write_last_request(path: &Path, time: SystemTime) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let nanos = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    fs::write(path, nanos.to_string())
+}
+
+fn config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| Some(PathBuf::from(env::var_os("HOME")?).join(".config")))
+    }
+}
+
+/// Gets the time at which a puzzle unlocks, which is midnight EST (UTC-5).
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+/// The number of puzzles in a year's event, which was reduced from 25 to 12 starting in 2025.
+pub fn days_in_year(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+pub fn check_unlocked(year: u32, day: u32) -> Result<(), FetchError> {
+    if !(1..=days_in_year(year)).contains(&day) || year < 2015 {
+        return Err(FetchError::InvalidDay { year, day });
+    }
+
+    if SystemTime::now() < unlock_time(year, day) {
+        return Err(FetchError::NotUnlocked { year, day });
+    }
+
+    Ok(())
+}
+
+/// Determines the verdict from the page returned after submitting an answer.
+pub fn parse_submit_response(html: &str) -> SubmitResponse {
+    let text = match html.split_once("<article") {
+        Some((_, article)) => article.split("</article>").next().unwrap(),
+        None => html,
+    };
+
+    let text = decode_entities(&strip_tags(&format!("<{text}")));
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooRecent
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text.clone())
+    };
+
+    SubmitResponse {
+        verdict,
+        wait: parse_wait(&text),
+    }
+}
+
+// The website says either "You have 1m 23s left to wait" or "please wait 5 minutes before trying
+// again".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, time) = before.rsplit_once("You have ")?;
+        let mut seconds = 0;
+        for part in time.split_whitespace() {
+            let (value, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            seconds += match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+        }
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let value = match words.next()? {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match words.next()? {
+        "second" | "seconds" => Some(Duration::from_secs(value)),
+        "minute" | "minutes" => Some(Duration::from_secs(value * 60)),
+        _ => None,
+    }
+}
+
+/// Removes all HTML tags from the text.
+pub fn strip_tags(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(ch),
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Replaces HTML character references with the characters they represent.
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let decoded = rest.find(';').and_then(|end| {
+            let ch = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix('x') {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+
+                    char::from_u32(code)?
+                }
+            };
+
+            Some((ch, end + 1))
+        });
+
+        match decoded {
+            Some((ch, len)) => {
+                result.push(ch);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+// The number of days since the Unix epoch for a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read},
+        net::TcpListener,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// Starts a server that answers a single request, and returns the base URL and a handle that
+    /// returns the request's lines.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = Vec::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+
+                if let Some(value) = line.trim().strip_prefix("Content-Length: ") {
+                    length = value.parse().unwrap();
+                }
+
+                request.push(line.trim().to_owned());
+            }
+
+            // The body, if there is one, is returned as the last line.
+            if length > 0 {
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.push(String::from_utf8(body).unwrap());
+            }
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, server)
+    }
+
+    /// Creates a client that doesn't share its last request time with other clients.
+    fn test_client() -> Client {
+        Client::new("secret").last_request_path(None)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("1 2\n3 4\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day5.txt");
+        let mut client = test_client().base_url(base_url).user_agent("test agent");
+
+        assert!(client.fetch_input(2023, 5, &path).unwrap());
+        assert_eq!("1 2\n3 4\n", fs::read_to_string(&path).unwrap());
+
+        let request = server.join().unwrap();
+        assert_eq!("GET /2023/day/5/input HTTP/1.1", request[0]);
+        assert!(request.iter().any(|line| line == "Cookie: session=secret"));
+        assert!(request.iter().any(|line| line == "User-Agent: test agent"));
+
+        // The file exists now, so it's not downloaded again (the server is gone, so this would
+        // fail if it tried).
+        assert!(!client.fetch_input(2023, 5, &path).unwrap());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once(
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+
+        let mut client = test_client().base_url(base_url);
+        let response = client.submit_answer(2023, 5, 2, "1234").unwrap();
+        assert_eq!(Verdict::Correct, response.verdict);
+        assert_eq!(None, response.wait);
+
+        let request = server.join().unwrap();
+        assert_eq!("POST /2023/day/5/answer HTTP/1.1", request[0]);
+        assert!(request.iter().any(|line| line == "Cookie: session=secret"));
+        assert_eq!("level=2&answer=1234", request.last().unwrap());
+    }
+
+    #[test]
+    fn test_parse_submit_response() {
+        let response = parse_submit_response(
+            "<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+            make sure you're using the full input data. Please wait one minute before trying \
+            again. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>",
+        );
+
+        assert_eq!(Verdict::TooHigh, response.verdict);
+        assert_eq!(Some(Duration::from_secs(60)), response.wait);
+
+        let response = parse_submit_response(
+            "<article><p>That's not the right answer; your answer is too low. Please wait 5 \
+            minutes before trying again.</p></article>",
+        );
+
+        assert_eq!(Verdict::TooLow, response.verdict);
+        assert_eq!(Some(Duration::from_secs(300)), response.wait);
+
+        let response =
+            parse_submit_response("<article><p>That's not the right answer.</p></article>");
+
+        assert_eq!(Verdict::Incorrect, response.verdict);
+        assert_eq!(None, response.wait);
+
+        let response = parse_submit_response(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an \
+            answer before trying again.  You have 1m 23s left to wait.</p></article>",
+        );
+
+        assert_eq!(Verdict::TooRecent, response.verdict);
+        assert_eq!(Some(Duration::from_secs(83)), response.wait);
+
+        let response = parse_submit_response(
+            "<article><p>You don't seem to be solving the right level.  Did you already complete \
+            it?</p></article>",
+        );
+
+        assert_eq!(Verdict::WrongLevel, response.verdict);
+        assert_eq!(
+            Verdict::Unknown("Something else".into()),
+            parse_submit_response("<article>Something <b>else</b></article>").verdict
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!("M<S & # &x", decode_entities("M&lt;S &amp; &#35; &x"));
+        assert_eq!("a b", strip_tags("<em>a</em> <a href=\"x\">b</a>"));
+    }
+
+    #[test]
+    fn test_not_unlocked() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = test_client().base_url("http://127.0.0.1:1");
+        let result = client.fetch_input(2999, 1, &dir.path().join("day1.txt"));
+        assert!(matches!(
+            result,
+            Err(FetchError::NotUnlocked { year: 2999, day: 1 })
+        ));
+
+        assert!(matches!(
+            check_unlocked(2023, 26),
+            Err(FetchError::InvalidDay { .. })
+        ));
+
+        assert!(matches!(
+            check_unlocked(2025, 13),
+            Err(FetchError::InvalidDay {
+                year: 2025,
+                day: 13
+            })
+        ));
+
+        assert!(check_unlocked(2025, 12).is_ok());
+        assert!(check_unlocked(2024, 25).is_ok());
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1701406800),
+            unlock_time(2023, 1)
+        );
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut client = test_client().min_delay(Duration::from_millis(50));
+        let start = Instant::now();
+        client.throttle();
+        client.throttle();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_throttle_shared() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc/last_request");
+        let new_client = || {
+            test_client()
+                .min_delay(Duration::from_millis(50))
+                .last_request_path(Some(path.clone()))
+        };
+
+        let start = Instant::now();
+        new_client().throttle();
+        assert!(path.exists());
+        new_client().throttle();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
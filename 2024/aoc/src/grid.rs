@@ -1,7 +1,10 @@
+mod bfs;
 mod builder;
 mod point;
 mod pointdiff;
 mod rect;
+mod region;
+mod sparse;
 mod subgrid;
 
 use std::{
@@ -9,6 +12,7 @@ use std::{
     ops::{Index, IndexMut},
 };
 
+pub use bfs::{Bfs, BfsSearch};
 pub use builder::GridBuilder;
 use ndarray::{
     iter::{AxisIter, AxisIterMut},
@@ -17,6 +21,8 @@ use ndarray::{
 pub use point::{Line, Neighbors, Point};
 pub use pointdiff::{PointDiff, Rotation};
 pub use rect::{DiffRectangle, Rectangle};
+pub use region::{Region, RegionId, Regions, Shape};
+pub use sparse::SparseGrid;
 pub use subgrid::SubGrid;
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
@@ -81,6 +87,22 @@ impl<T: Clone> Grid<T> {
             }
         }
     }
+
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate(&self, rotation: Rotation) -> Self {
+        self.view().rotate(rotation).to_grid()
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
 }
 
 impl<T> Grid<T> {
@@ -159,6 +181,25 @@ impl<T> Grid<T> {
         SubGrid::new(self, bounds)
     }
 
+    /// A view of the whole grid, which can be transformed without copying.
+    pub fn view(&self) -> SubGrid<'_, T> {
+        SubGrid::from(self)
+    }
+
+    /// Views of the grid in each of its 8 orientations: the 4 rotations clockwise, starting with
+    /// the grid itself, and then the 4 rotations of the grid flipped horizontally.
+    pub fn symmetries(&self) -> impl Iterator<Item = SubGrid<'_, T>> {
+        (0..8).map(move |index| {
+            let view = if index < 4 {
+                self.view()
+            } else {
+                self.view().flip_horizontal()
+            };
+
+            (0..index % 4).fold(view, |view, _| view.rotate(Rotation::Right))
+        })
+    }
+
     pub fn edge_cells(&self) -> impl Iterator<Item = (Point, &T)> {
         // This could obviously be done much more efficiently.
         let last_row = self.height() - 1;
@@ -256,7 +297,7 @@ impl<T> Grid<T> {
         Some(Point::new(self.height() - row - 1, col))
     }
 
-    pub fn scan(&self, start: Point, direction: PointDiff) -> Scan<T> {
+    pub fn scan(&self, start: Point, direction: PointDiff) -> Scan<'_, T> {
         Scan {
             grid: self,
             current: Some(start),
@@ -264,7 +305,7 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn scan_mut(&mut self, start: Point, direction: PointDiff) -> ScanMut<T> {
+    pub fn scan_mut(&mut self, start: Point, direction: PointDiff) -> ScanMut<'_, T> {
         ScanMut {
             grid: self,
             current: Some(start),
@@ -457,6 +498,7 @@ impl Edge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::AocInput;
 
     #[test]
     fn test_straight_neighbors() {
@@ -492,4 +534,68 @@ mod tests {
         assert_eq!(b"12345", grid.0.slice(s![1, ..]).as_slice().unwrap());
         assert_eq!(b"12   ", grid.0.slice(s![2, ..]).as_slice().unwrap());
     }
+
+    #[test]
+    fn test_from_bytes() {
+        let grid = GridBuilder::from_bytes(b"12\r\n34\r\n56\r\n")
+            .numbers()
+            .build();
+        assert_eq!(2, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!(&[1, 2, 3, 4, 5, 6], grid.0.as_slice().unwrap());
+
+        // The last line doesn't need a line break.
+        let text = AocInput::from_str("#..\n.#.").into_text();
+        let expected = GridBuilder::from_input(AocInput::from_str("#..\n.#.")).build();
+        assert_eq!(
+            expected.0,
+            GridBuilder::from_bytes(&text.as_bytes()[..7]).build().0
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = GridBuilder::from_input(AocInput::from_str("abc\ndef")).build();
+        let to_string = |grid: Grid<u8>| grid.map(|&b| b as char).to_string();
+        assert_eq!("ad\nbe\ncf\n", to_string(grid.transpose()));
+        assert_eq!("da\neb\nfc\n", to_string(grid.rotate(Rotation::Right)));
+        assert_eq!("cf\nbe\nad\n", to_string(grid.rotate(Rotation::Left)));
+        assert_eq!("cba\nfed\n", to_string(grid.flip_horizontal()));
+        assert_eq!("def\nabc\n", to_string(grid.flip_vertical()));
+
+        let view = grid.view().rotate(Rotation::Right);
+        assert_eq!(3, view.height());
+        assert_eq!(b'a', view[Point::new(0, 1)]);
+        assert_eq!(Some(&b'f'), view.get(Point::new(2, 0)));
+        assert_eq!(None, view.get(Point::new(0, 2)));
+
+        let symmetries = grid
+            .symmetries()
+            .map(|view| to_string(view.to_grid()))
+            .collect::<Vec<_>>();
+        assert_eq!(8, symmetries.len());
+        assert_eq!("abc\ndef\n", symmetries[0]);
+        assert_eq!("fed\ncba\n", symmetries[2]);
+        assert_eq!("cba\nfed\n", symmetries[4]);
+        assert_eq!("fc\neb\nda\n", symmetries[5]);
+        assert_eq!(to_string(grid.transpose()), symmetries[7]);
+        assert!(symmetries[1..].iter().all(|s| *s != symmetries[0]));
+    }
+
+    #[test]
+    fn test_transforms_empty() {
+        let grid = Grid::new(0, 0, b'.');
+        assert_eq!(0, grid.view().height());
+        assert_eq!(0, grid.transpose().width());
+        assert_eq!(0, grid.rotate(Rotation::Right).height());
+        assert_eq!(0, grid.flip_horizontal().width());
+        assert_eq!(0, grid.flip_vertical().height());
+        assert_eq!(8, grid.symmetries().count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bytes_non_uniform() {
+        GridBuilder::from_bytes(b"123\n12\n").build();
+    }
 }
@@ -0,0 +1,225 @@
+use std::collections::VecDeque;
+
+use super::{Grid, Point, PointDiff};
+
+/// The result of a breadth-first search on a grid.
+#[derive(Clone, Debug)]
+pub struct Bfs {
+    distances: Grid<Option<usize>>,
+    predecessors: Grid<Option<Point>>,
+    target: Option<Point>,
+}
+
+impl Bfs {
+    /// The number of steps from the nearest source to each point, or `None` for points that
+    /// weren't reached.
+    pub fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> Grid<Option<usize>> {
+        self.distances
+    }
+
+    pub fn distance(&self, point: Point) -> Option<usize> {
+        self.distances[point]
+    }
+
+    /// The point each point was reached from; `None` for sources and unreached points.
+    pub fn predecessors(&self) -> &Grid<Option<Point>> {
+        &self.predecessors
+    }
+
+    /// The target found by a search that stopped early.
+    pub fn target(&self) -> Option<Point> {
+        self.target
+    }
+
+    /// A shortest path from a source to the point, including both ends.
+    pub fn path(&self, point: Point) -> Option<Vec<Point>> {
+        self.distances[point]?;
+        let mut path = vec![point];
+        let mut current = point;
+        while let Some(prev) = self.predecessors[current] {
+            path.push(prev);
+            current = prev;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+type Target<'a, T> = Box<dyn FnMut(Point, &T) -> bool + 'a>;
+
+/// Settings for a breadth-first search, created by [`Grid::bfs_search`].
+pub struct BfsSearch<'a, T> {
+    grid: &'a Grid<T>,
+    sources: Vec<Point>,
+    neighbors: &'a [PointDiff],
+    wrapped: bool,
+    target: Option<Target<'a, T>>,
+}
+
+impl<'a, T> BfsSearch<'a, T> {
+    /// Uses other neighbors than the default straight neighbors.
+    pub fn neighbors(mut self, neighbors: &'a [PointDiff]) -> Self {
+        self.neighbors = neighbors;
+        self
+    }
+
+    /// Wraps around the edges of the grid.
+    pub fn wrapped(mut self) -> Self {
+        self.wrapped = true;
+        self
+    }
+
+    /// Stops the search once it reaches a point matching `target`. Points that are the same
+    /// distance from a source as the target may not have been reached yet.
+    pub fn until(mut self, target: impl FnMut(Point, &T) -> bool + 'a) -> Self {
+        self.target = Some(Box::new(target));
+        self
+    }
+
+    /// Runs the search, only moving between neighboring cells where `passable` returns true for
+    /// the values of the current cell and the next one.
+    pub fn run(mut self, mut passable: impl FnMut(&T, &T) -> bool) -> Bfs {
+        let grid = self.grid;
+        let mut result = Bfs {
+            distances: Grid::new(grid.height(), grid.width(), None),
+            predecessors: Grid::new(grid.height(), grid.width(), None),
+            target: None,
+        };
+
+        let sources = std::mem::take(&mut self.sources);
+        let mut queue = VecDeque::new();
+        for &source in &sources {
+            if result.distances[source].is_none() {
+                result.distances[source] = Some(0);
+                queue.push_back(source);
+            }
+        }
+
+        result.target = sources.into_iter().find(|&source| self.is_target(source));
+        if result.target.is_some() {
+            return result;
+        }
+
+        let mut neighbors = Vec::new();
+        while let Some(pos) = queue.pop_front() {
+            let distance = result.distances[pos].unwrap() + 1;
+            neighbors.clear();
+            if self.wrapped {
+                neighbors.extend(grid.neighbors_wrapped(pos, self.neighbors));
+            } else {
+                neighbors.extend(grid.neighbors(pos, self.neighbors));
+            }
+
+            for &nb in &neighbors {
+                if result.distances[nb].is_some() || !passable(&grid[pos], &grid[nb]) {
+                    continue;
+                }
+
+                result.distances[nb] = Some(distance);
+                result.predecessors[nb] = Some(pos);
+                if self.is_target(nb) {
+                    result.target = Some(nb);
+                    return result;
+                }
+
+                queue.push_back(nb);
+            }
+        }
+
+        result
+    }
+
+    fn is_target(&mut self, point: Point) -> bool {
+        let grid = self.grid;
+        self.target
+            .as_mut()
+            .is_some_and(|target| target(point, &grid[point]))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds the shortest distance from any of the sources to every point, moving between
+    /// straight neighbors where `passable` returns true for the values of the current cell and
+    /// the next one.
+    pub fn bfs(
+        &self,
+        sources: impl IntoIterator<Item = Point>,
+        passable: impl FnMut(&T, &T) -> bool,
+    ) -> Bfs {
+        self.bfs_search(sources).run(passable)
+    }
+
+    /// Creates a breadth-first search that can be customized before running it.
+    pub fn bfs_search(&self, sources: impl IntoIterator<Item = Point>) -> BfsSearch<'_, T> {
+        BfsSearch {
+            grid: self,
+            sources: sources.into_iter().collect(),
+            neighbors: &PointDiff::STRAIGHT_NEIGHBORS,
+            wrapped: false,
+            target: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::GridBuilder, input::AocInput};
+
+    #[test]
+    fn test_bfs() {
+        let grid = GridBuilder::from_input(AocInput::from_str("S.#.\n.##.\n....")).build();
+        let bfs = grid.bfs([Point::new(0, 0)], |_, &to| to != b'#');
+        assert_eq!(Some(0), bfs.distance(Point::new(0, 0)));
+        assert_eq!(Some(7), bfs.distance(Point::new(0, 3)));
+        assert_eq!(None, bfs.distance(Point::new(0, 2)));
+        assert_eq!(None, bfs.target());
+        assert_eq!(
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1)
+            ],
+            bfs.path(Point::new(2, 1)).unwrap()
+        );
+
+        // With two sources, each point gets the distance to the nearest one.
+        let bfs = grid.bfs([Point::new(0, 0), Point::new(0, 3)], |_, &to| to != b'#');
+        assert_eq!(Some(3), bfs.distance(Point::new(2, 1)));
+        assert_eq!(Some(2), bfs.distance(Point::new(2, 3)));
+        assert_eq!(None, bfs.predecessors()[Point::new(0, 3)]);
+    }
+
+    #[test]
+    fn test_bfs_search() {
+        let grid = GridBuilder::from_input(AocInput::from_str("abc\nfed\nghi")).build();
+        let bfs = grid
+            .bfs_search([Point::new(0, 0)])
+            .until(|_, &cell| cell == b'e')
+            .run(|&from, &to| to <= from + 1);
+
+        assert_eq!(Some(Point::new(1, 1)), bfs.target());
+        assert_eq!(Some(4), bfs.distance(Point::new(1, 1)));
+        assert_eq!(None, bfs.distance(Point::new(2, 2)));
+
+        let bfs = grid
+            .bfs_search([Point::new(0, 0)])
+            .wrapped()
+            .run(|_, _| true);
+        assert_eq!(Some(1), bfs.distance(Point::new(2, 0)));
+        assert_eq!(Some(2), bfs.distance(Point::new(2, 2)));
+
+        let bfs = grid
+            .bfs_search([Point::new(0, 0)])
+            .neighbors(&PointDiff::ALL_NEIGHBORS)
+            .run(|_, _| true);
+        assert_eq!(Some(2), bfs.distance(Point::new(2, 2)));
+        assert_eq!(2, bfs.path(Point::new(2, 1)).unwrap().len() - 1);
+    }
+}
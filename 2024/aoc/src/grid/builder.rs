@@ -8,7 +8,7 @@ use super::Point;
 pub struct GridBuilder<T, I, S>
 where
     I: Iterator<Item = S>,
-    S: AsRef<str>,
+    S: AsRef<[u8]>,
 {
     input: I,
     transform: Box<dyn FnMut(Point, u8) -> T>,
@@ -28,7 +28,7 @@ impl GridBuilder<u8, AocInput, String> {
 impl<I, S> GridBuilder<u8, I, S>
 where
     I: Iterator<Item = S>,
-    S: AsRef<str>,
+    S: AsRef<[u8]>,
 {
     pub fn from_lines(input: I) -> Self {
         Self {
@@ -42,7 +42,7 @@ where
 impl<T, I, S> GridBuilder<T, I, S>
 where
     I: Iterator<Item = S>,
-    S: AsRef<str>,
+    S: AsRef<[u8]>,
 {
     pub fn map<U>(
         self,
@@ -73,7 +73,10 @@ where
     }
 
     pub fn build(mut self) -> Grid<T> {
-        let (extend_width, extend_height, extend_value) = self.extend.unwrap_or((0, 0, 0));
+        let Some((extend_width, extend_height, extend_value)) = self.extend else {
+            return self.build_uniform();
+        };
+
         let mut grid: Vec<_> = (0..extend_height).map(|_| Vec::new()).collect();
 
         let mut row_index = extend_height;
@@ -82,7 +85,7 @@ where
                 .map(|col| (self.transform)(Point::new(row_index, col), extend_value))
                 .collect();
 
-            row.extend(line.as_ref().bytes().enumerate().map(|(col, value)| {
+            row.extend(line.as_ref().iter().enumerate().map(|(col, &value)| {
                 (self.transform)(Point::new(row_index, col + extend_width), value)
             }));
 
@@ -94,17 +97,15 @@ where
             row
         }));
 
-        if self.extend.is_some() {
-            let max_width = grid.iter().map(|row| row.len()).max().unwrap();
-            grid.extend((0..extend_height).map(|_| Vec::new()));
-            for (row_index, row) in grid.iter_mut().enumerate() {
-                let mut col = row.len();
-                row.resize_with(max_width, || {
-                    let result = (self.transform)(Point::new(row_index, col), extend_value);
-                    col += 1;
-                    result
-                })
-            }
+        let max_width = grid.iter().map(|row| row.len()).max().unwrap();
+        grid.extend((0..extend_height).map(|_| Vec::new()));
+        for (row_index, row) in grid.iter_mut().enumerate() {
+            let mut col = row.len();
+            row.resize_with(max_width, || {
+                let result = (self.transform)(Point::new(row_index, col), extend_value);
+                col += 1;
+                result
+            })
         }
 
         let max_width = grid.iter().map(|row| row.len()).max().unwrap();
@@ -115,4 +116,101 @@ where
                 .unwrap(),
         )
     }
+
+    // Builds the grid directly into a single buffer, which requires all rows to be the same
+    // width.
+    fn build_uniform(mut self) -> Grid<T> {
+        let mut values = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let rows = self.input.size_hint().0;
+        for line in self.input {
+            let line = line.as_ref();
+            let width = *width.get_or_insert(line.len());
+            assert_eq!(width, line.len(), "row {height} has a different width");
+            if values.capacity() == 0 {
+                values.reserve(width * rows);
+            }
+
+            values.extend(
+                line.iter()
+                    .enumerate()
+                    .map(|(col, &value)| (self.transform)(Point::new(height, col), value)),
+            );
+
+            height += 1;
+        }
+
+        Grid(Array::from_shape_vec((height, width.unwrap_or(0)), values).unwrap())
+    }
+}
+
+/// The rows of a grid stored in a byte buffer, such as the contents of an input file. Each row
+/// must have the same length, so the rows are found using the stride rather than by searching
+/// for line breaks.
+pub struct ByteRows<'a> {
+    data: &'a [u8],
+    width: usize,
+    stride: usize,
+}
+
+impl<'a> ByteRows<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        let (width, stride) = match data.iter().position(|&b| b == b'\n') {
+            Some(index) if index > 0 && data[index - 1] == b'\r' => (index - 1, index + 1),
+            Some(index) => (index, index + 1),
+            None => (data.len(), data.len()),
+        };
+
+        Self {
+            data,
+            width,
+            stride,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl<'a> Iterator for ByteRows<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() || self.stride == 0 {
+            return None;
+        }
+
+        let row = &self.data[..self.width.min(self.data.len())];
+        let end = self.stride.min(self.data.len());
+        assert!(
+            row.len() == self.width
+                && !row.contains(&b'\n')
+                && self.data[row.len()..end]
+                    .iter()
+                    .all(|b| b"\r\n".contains(b)),
+            "all rows must have the same width"
+        );
+
+        self.data = &self.data[end..];
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rows = self.data.len().div_ceil(self.stride.max(1));
+        (rows, Some(rows))
+    }
+}
+
+impl<'a> GridBuilder<u8, ByteRows<'a>, &'a [u8]> {
+    /// Builds a grid from a buffer holding the whole input, without splitting it into lines
+    /// first.
+    pub fn from_bytes(data: &'a [u8]) -> Self {
+        Self {
+            input: ByteRows::new(data),
+            transform: Box::new(|_, byte| byte),
+            extend: None,
+        }
+    }
 }
@@ -0,0 +1,455 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Display,
+};
+
+use super::{Grid, Point, PointDiff, Rectangle, Rotation};
+
+/// Identifies a region found by [`Grid::regions`]; ids are assigned in row-major order of each
+/// region's first cell, starting from zero.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Debug)]
+pub struct RegionId(usize);
+
+impl RegionId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+impl Display for RegionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Region {
+    id: RegionId,
+    start: Point,
+    area: usize,
+    bounds: Rectangle,
+}
+
+impl Region {
+    pub fn id(&self) -> RegionId {
+        self.id
+    }
+
+    /// The first cell of the region in row-major order.
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// The number of cells in the region.
+    pub fn area(&self) -> usize {
+        self.area
+    }
+
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+/// The connected regions of a grid, with a grid that has the id of the region of each cell.
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Grid<RegionId>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn labels(&self) -> &Grid<RegionId> {
+        &self.labels
+    }
+
+    pub fn into_labels(self) -> Grid<RegionId> {
+        self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn region(&self, id: RegionId) -> &Region {
+        &self.regions[id.0]
+    }
+
+    /// Gets the region that contains a point.
+    pub fn region_at(&self, point: Point) -> &Region {
+        self.region(self.labels[point])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    /// The cells of a region, in row-major order.
+    pub fn points(&self, id: RegionId) -> impl Iterator<Item = Point> + '_ {
+        self.region(id)
+            .bounds
+            .points()
+            .filter(move |&point| self.labels[point] == id)
+    }
+
+    pub fn shape(&self, id: RegionId) -> Shape {
+        Shape::from_points(self.points(id))
+    }
+}
+
+/// A set of points, treated as the cells they cover, for measuring the outline of a region.
+///
+/// Boundaries are made of the corners of cells, where corner `(row, col)` is the top left corner
+/// of the cell at `(row, col)`.
+#[derive(Clone, Debug, Default)]
+pub struct Shape {
+    points: HashSet<Point>,
+}
+
+impl Shape {
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.points.contains(&point)
+    }
+
+    /// The number of cell edges between a cell in the shape and one outside it.
+    pub fn perimeter(&self) -> usize {
+        self.exposed_edges().count()
+    }
+
+    /// The number of straight sides, including the sides of holes. Edges that only touch at a
+    /// corner are separate sides.
+    pub fn sides(&self) -> usize {
+        self.exposed_edges()
+            .filter(|&(point, dir)| {
+                // Only count the edge at the start of each side.
+                let along = dir.rotate(Rotation::Left);
+                match point.add_diff(along).filter(|&prev| self.contains(prev)) {
+                    Some(prev) => self.has_neighbor(prev, dir),
+                    None => true,
+                }
+            })
+            .count()
+    }
+
+    /// The number of corners on the outline, including the corners of holes. A corner where two
+    /// cells of the shape only touch diagonally counts twice.
+    pub fn corners(&self) -> usize {
+        self.points
+            .iter()
+            .map(|&point| {
+                PointDiff::STRAIGHT_NEIGHBORS
+                    .iter()
+                    .filter(|&&dir| {
+                        let next = dir.rotate(Rotation::Right);
+                        let (a, b) = (
+                            self.has_neighbor(point, dir),
+                            self.has_neighbor(point, next),
+                        );
+
+                        // Either a convex or a concave corner.
+                        (!a && !b) || (a && b && !self.has_neighbor(point, dir + next))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The outer boundaries of the shape, one for each part of it that is connected through
+    /// straight neighbors. Each is a loop of the corners on the outline, going clockwise from the
+    /// top left corner.
+    pub fn boundaries(&self) -> Vec<Vec<Point>> {
+        self.loops().into_iter().filter(|l| area(l) > 0).collect()
+    }
+
+    /// The boundaries of the holes in the shape, going counter-clockwise. Holes that touch
+    /// diagonally share a boundary, which passes through the corner between them twice.
+    pub fn holes(&self) -> Vec<Vec<Point>> {
+        self.loops().into_iter().filter(|l| area(l) < 0).collect()
+    }
+
+    fn has_neighbor(&self, point: Point, dir: PointDiff) -> bool {
+        point.add_diff(dir).is_some_and(|nb| self.contains(nb))
+    }
+
+    // Each cell edge on the outline, as the cell and the direction that leaves the shape.
+    fn exposed_edges(&self) -> impl Iterator<Item = (Point, PointDiff)> + '_ {
+        self.points.iter().flat_map(move |&point| {
+            PointDiff::STRAIGHT_NEIGHBORS
+                .into_iter()
+                .filter(move |&dir| !self.has_neighbor(point, dir))
+                .map(move |dir| (point, dir))
+        })
+    }
+
+    fn loops(&self) -> Vec<Vec<Point>> {
+        // The edges as a start corner and a direction, going clockwise around the shape.
+        let all = self
+            .exposed_edges()
+            .map(|(point, dir)| {
+                let offset = match dir {
+                    PointDiff::UP => PointDiff::ZERO,
+                    PointDiff::RIGHT => PointDiff::RIGHT,
+                    PointDiff::DOWN => PointDiff::DOWN_RIGHT,
+                    _ => PointDiff::DOWN,
+                };
+
+                (point.add_diff(offset).unwrap(), dir.rotate(Rotation::Right))
+            })
+            .collect::<HashSet<_>>();
+
+        let mut remaining = all.iter().copied().collect::<BTreeSet<_>>();
+        let mut result = Vec::new();
+        while let Some(first) = remaining.pop_first() {
+            let mut corners = Vec::new();
+            let (mut corner, mut dir) = first;
+            loop {
+                let next = corner.add_diff(dir).unwrap();
+                // Turning right first keeps cells that only touch diagonally apart.
+                let next_dir = [dir.rotate(Rotation::Right), dir, dir.rotate(Rotation::Left)]
+                    .into_iter()
+                    .find(|&d| all.contains(&(next, d)))
+                    .unwrap();
+
+                if next_dir != dir {
+                    corners.push(next);
+                }
+
+                if (next, next_dir) == first {
+                    break;
+                }
+
+                remaining.remove(&(next, next_dir));
+                (corner, dir) = (next, next_dir);
+            }
+
+            // The loop ends at the first corner, so move it to the start.
+            corners.rotate_right(1);
+            result.push(corners);
+        }
+
+        result
+    }
+}
+
+// Twice the signed area of a loop, which is positive if it goes clockwise.
+fn area(corners: &[Point]) -> isize {
+    corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            let (a, b) = (a.into_diff().unwrap(), b.into_diff().unwrap());
+            a.col() * b.row() - b.col() * a.row()
+        })
+        .sum()
+}
+
+impl<T> Grid<T> {
+    /// Finds all the points reachable from `start` by moving to the given neighbors, only
+    /// entering cells that are `passable`. The start is always included, and the points are
+    /// returned in the order they were reached.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        neighbors: &[PointDiff],
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Vec<Point> {
+        let mut visited = Grid::new(self.height(), self.width(), false);
+        visited[start] = true;
+        let mut result = vec![start];
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            for nb in self.neighbors(pos, neighbors) {
+                if !visited[nb] && passable(&self[nb]) {
+                    visited[nb] = true;
+                    result.push(nb);
+                    stack.push(nb);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Labels every connected region of the grid, where two neighboring cells are in the same
+    /// region if `same_region` returns true for their values.
+    pub fn regions(
+        &self,
+        neighbors: &[PointDiff],
+        mut same_region: impl FnMut(&T, &T) -> bool,
+    ) -> Regions {
+        let mut labels: Grid<Option<RegionId>> = Grid::new(self.height(), self.width(), None);
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+        for start in self.bounding_rect().points() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            let id = RegionId(regions.len());
+            labels[start] = Some(id);
+            stack.push(start);
+            let mut area = 0;
+            let (mut top_left, mut bottom_right) = (start, start);
+            while let Some(pos) = stack.pop() {
+                area += 1;
+                top_left = Point::new(top_left.row().min(pos.row()), top_left.col().min(pos.col()));
+                bottom_right = Point::new(
+                    bottom_right.row().max(pos.row()),
+                    bottom_right.col().max(pos.col()),
+                );
+
+                for nb in self.neighbors(pos, neighbors) {
+                    if labels[nb].is_none() && same_region(&self[pos], &self[nb]) {
+                        labels[nb] = Some(id);
+                        stack.push(nb);
+                    }
+                }
+            }
+
+            regions.push(Region {
+                id,
+                start,
+                area,
+                bounds: Rectangle::new(top_left, bottom_right),
+            });
+        }
+
+        Regions {
+            labels: labels.map(|id| id.unwrap()),
+            regions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::GridBuilder, input::AocInput};
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = GridBuilder::from_input(AocInput::from_str("..#\n.#.\n#..")).build();
+        let mut points = grid.flood_fill(Point::new(0, 0), &PointDiff::STRAIGHT_NEIGHBORS, |&c| {
+            c == b'.'
+        });
+
+        points.sort();
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 0)],
+            points
+        );
+
+        let points = grid.flood_fill(Point::new(0, 0), &PointDiff::ALL_NEIGHBORS, |&c| c == b'.');
+        assert_eq!(6, points.len());
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = GridBuilder::from_input(AocInput::from_str("AAAA\nBBCD\nBBCC\nEEEC")).build();
+        let regions = grid.regions(&PointDiff::STRAIGHT_NEIGHBORS, |a, b| a == b);
+        assert_eq!(5, regions.len());
+        assert_eq!(
+            vec![4, 4, 4, 1, 3],
+            regions.iter().map(Region::area).collect::<Vec<_>>()
+        );
+
+        let c = regions.region_at(Point::new(1, 2));
+        assert_eq!(RegionId(2), c.id());
+        assert_eq!(Point::new(1, 2), c.bounds().top_left());
+        assert_eq!(Point::new(3, 3), c.bounds().bottom_right());
+        assert_eq!(
+            vec![
+                Point::new(1, 2),
+                Point::new(2, 2),
+                Point::new(2, 3),
+                Point::new(3, 3)
+            ],
+            regions.points(c.id()).collect::<Vec<_>>()
+        );
+
+        // Diagonal neighbors join the two regions of dots.
+        let grid = GridBuilder::from_input(AocInput::from_str(".#\n#.")).build();
+        assert_eq!(
+            4,
+            grid.regions(&PointDiff::STRAIGHT_NEIGHBORS, |a, b| a == b)
+                .len()
+        );
+
+        let regions = grid.regions(&PointDiff::ALL_NEIGHBORS, |a, b| a == b);
+        assert_eq!(2, regions.len());
+        assert_eq!("01\n10\n", regions.labels().to_string());
+    }
+
+    #[test]
+    fn test_shape() {
+        let grid = GridBuilder::from_input(AocInput::from_str(
+            "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA",
+        ))
+        .build();
+        let regions = grid.regions(&PointDiff::STRAIGHT_NEIGHBORS, |a, b| a == b);
+        let shape = regions.shape(RegionId(0));
+        assert_eq!(28, shape.area());
+        assert_eq!(40, shape.perimeter());
+        assert_eq!(12, shape.sides());
+        assert_eq!(12, shape.corners());
+        assert_eq!(
+            vec![vec![
+                Point::new(0, 0),
+                Point::new(0, 6),
+                Point::new(6, 6),
+                Point::new(6, 0)
+            ]],
+            shape.boundaries()
+        );
+
+        // The two holes touch diagonally, so their boundaries join at the corner they share.
+        assert_eq!(
+            vec![vec![
+                Point::new(1, 3),
+                Point::new(3, 3),
+                Point::new(3, 1),
+                Point::new(5, 1),
+                Point::new(5, 3),
+                Point::new(3, 3),
+                Point::new(3, 5),
+                Point::new(1, 5)
+            ]],
+            shape.holes()
+        );
+
+        let shape = Shape::from_points([Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)]);
+        assert_eq!(8, shape.perimeter());
+        assert_eq!(6, shape.sides());
+        assert_eq!(6, shape.corners());
+        assert_eq!(
+            vec![vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(1, 2),
+                Point::new(2, 2),
+                Point::new(2, 0)
+            ]],
+            shape.boundaries()
+        );
+        assert!(shape.holes().is_empty());
+
+        let shape = Shape::from_points([Point::new(0, 0), Point::new(1, 1)]);
+        assert_eq!(8, shape.sides());
+        assert_eq!(8, shape.corners());
+        assert_eq!(2, shape.boundaries().len());
+    }
+}
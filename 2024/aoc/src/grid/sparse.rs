@@ -0,0 +1,242 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::{DiffRectangle, Grid, PointDiff};
+
+/// An unbounded grid with signed coordinates, which only stores the cells that have been set.
+/// All other cells have the default value.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<PointDiff, T>,
+    default: T,
+    bounds: Option<DiffRectangle>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// The smallest rectangle containing all the cells that have been set, or `None` if there
+    /// are none.
+    pub fn bounds(&self) -> Option<DiffRectangle> {
+        self.bounds
+    }
+
+    pub fn contains(&self, point: PointDiff) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Gets the value of a cell, which is the default value if it hasn't been set.
+    pub fn get(&self, point: PointDiff) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Sets a cell, returning its previous value if it was set.
+    pub fn insert(&mut self, point: PointDiff, value: T) -> Option<T> {
+        self.grow_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Unsets a cell, so that it has the default value again.
+    pub fn remove(&mut self, point: PointDiff) -> Option<T> {
+        let result = self.cells.remove(&point)?;
+        let bounds = self.bounds.unwrap();
+        let on_edge = point.row() == bounds.top_left().row()
+            || point.row() == bounds.bottom_right().row()
+            || point.col() == bounds.top_left().col()
+            || point.col() == bounds.bottom_right().col();
+
+        if on_edge {
+            self.bounds =
+                (!self.cells.is_empty()).then(|| DiffRectangle::from_points(self.cells.keys()));
+        }
+
+        Some(result)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (PointDiff, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    pub fn straight_neighbors(&self, point: PointDiff) -> impl Iterator<Item = (PointDiff, &T)> {
+        self.neighbors(point, &PointDiff::STRAIGHT_NEIGHBORS)
+    }
+
+    pub fn all_neighbors(&self, point: PointDiff) -> impl Iterator<Item = (PointDiff, &T)> {
+        self.neighbors(point, &PointDiff::ALL_NEIGHBORS)
+    }
+
+    /// The neighbors of a point and their values, including neighbors that haven't been set.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: PointDiff,
+        neighbors: &'a [PointDiff],
+    ) -> impl Iterator<Item = (PointDiff, &'a T)> + 'a {
+        point.neighbors(neighbors).map(|nb| (nb, self.get(nb)))
+    }
+
+    /// Converts to a dense grid covering the bounds, where the top left of the bounds is at
+    /// `(0, 0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no cells have been set.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds.expect("the grid is empty");
+        let mut grid = Grid::new(bounds.height(), bounds.width(), self.default.clone());
+        for (point, value) in &self.cells {
+            let pos = (*point - bounds.top_left()).into_point().unwrap();
+            grid[pos] = value.clone();
+        }
+
+        grid
+    }
+
+    fn grow_bounds(&mut self, point: PointDiff) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) if bounds.contains(point) => return,
+            Some(bounds) => DiffRectangle::new(
+                PointDiff::new(
+                    bounds.top_left().row().min(point.row()),
+                    bounds.top_left().col().min(point.col()),
+                ),
+                PointDiff::new(
+                    bounds.bottom_right().row().max(point.row()),
+                    bounds.bottom_right().col().max(point.col()),
+                ),
+            ),
+            None => DiffRectangle::new(point, point),
+        });
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Index<PointDiff> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: PointDiff) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+/// Indexing mutably sets the cell to the default value if it wasn't set.
+impl<T: Clone> IndexMut<PointDiff> for SparseGrid<T> {
+    fn index_mut(&mut self, index: PointDiff) -> &mut Self::Output {
+        self.grow_bounds(index);
+        self.cells
+            .entry(index)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+impl<T> Extend<(PointDiff, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (PointDiff, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T: Default> FromIterator<(PointDiff, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (PointDiff, T)>>(iter: I) -> Self {
+        let mut grid = Self::default();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+
+        for row in bounds.top_left().row()..=bounds.bottom_right().row() {
+            for col in bounds.top_left().col()..=bounds.bottom_right().col() {
+                write!(f, "{}", self.get(PointDiff::new(row, col)))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(None, grid.bounds().map(|b| b.top_left()));
+        assert_eq!("", grid.to_string());
+
+        grid.insert(PointDiff::new(-1, 2), '#');
+        grid.insert(PointDiff::new(1, -1), '#');
+        grid[PointDiff::new(0, 0)] = 'x';
+        assert_eq!(3, grid.len());
+        assert_eq!('.', grid[PointDiff::new(5, 5)]);
+        assert_eq!(PointDiff::new(-1, -1), grid.bounds().unwrap().top_left());
+        assert_eq!(PointDiff::new(1, 2), grid.bounds().unwrap().bottom_right());
+        assert_eq!("...#\n.x..\n#...\n", grid.to_string());
+        assert_eq!(grid.to_string(), grid.to_grid().to_string());
+
+        assert_eq!(
+            vec!['.', '.', '.', '.', '.', '.', '#', '.'],
+            grid.all_neighbors(PointDiff::ZERO)
+                .map(|(_, &value)| value)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(Some('#'), grid.remove(PointDiff::new(-1, 2)));
+        assert_eq!(None, grid.remove(PointDiff::new(-1, 2)));
+        assert_eq!(PointDiff::new(0, -1), grid.bounds().unwrap().top_left());
+        assert_eq!(PointDiff::new(1, 0), grid.bounds().unwrap().bottom_right());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        // Counting the houses visited on a walk.
+        let mut pos = PointDiff::ZERO;
+        let mut houses: SparseGrid<usize> = [(pos, 1)].into_iter().collect();
+        for dir in "^>v<".bytes().map(|b| PointDiff::from_arrows(b).unwrap()) {
+            pos += dir;
+            houses[pos] += 1;
+        }
+
+        assert_eq!(4, houses.len());
+        assert_eq!(2, houses[PointDiff::ZERO]);
+        assert_eq!("11\n21\n", houses.to_string());
+    }
+}
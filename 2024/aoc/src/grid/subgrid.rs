@@ -1,9 +1,11 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::Index};
 
-use ndarray::{iter::Lanes, prelude::*};
+use ndarray::{iter::Lanes, prelude::*, IndexLonger};
 
-use super::{Grid, Rectangle};
+use super::{Grid, Point, Rectangle, Rotation};
 
+/// A read-only view of part or all of a grid, which may also be transformed without copying.
+#[derive(Clone, Debug)]
 pub struct SubGrid<'a, T>(ArrayView2<'a, T>);
 
 impl<'a, T> SubGrid<'a, T> {
@@ -14,9 +16,69 @@ impl<'a, T> SubGrid<'a, T> {
         ]))
     }
 
+    pub fn width(&self) -> usize {
+        self.0.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.nrows()
+    }
+
+    pub fn get(&self, index: Point) -> Option<&'a T> {
+        // Unlike `ArrayView::get`, this gives a reference that lives as long as the grid.
+        IndexLonger::get(&self.0, (index.row(), index.col()))
+    }
+
     pub fn rows(&self) -> Lanes<'_, T, Dim<[usize; 1]>> {
         self.0.rows()
     }
+
+    /// Swaps rows and columns.
+    pub fn transpose(self) -> Self {
+        Self(self.0.reversed_axes())
+    }
+
+    /// Rotates by 90 degrees; `Rotation::Right` is clockwise.
+    pub fn rotate(self, rotation: Rotation) -> Self {
+        match rotation {
+            Rotation::Right => self.transpose().flip_horizontal(),
+            Rotation::Left => self.transpose().flip_vertical(),
+        }
+    }
+
+    /// Mirrors left to right, reversing the order of the columns.
+    pub fn flip_horizontal(mut self) -> Self {
+        self.0.invert_axis(Axis(1));
+        self
+    }
+
+    /// Mirrors top to bottom, reversing the order of the rows.
+    pub fn flip_vertical(mut self) -> Self {
+        self.0.invert_axis(Axis(0));
+        self
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid(self.0.as_standard_layout().into_owned())
+    }
+}
+
+/// A view of the whole grid.
+impl<'a, T> From<&'a Grid<T>> for SubGrid<'a, T> {
+    fn from(grid: &'a Grid<T>) -> Self {
+        Self(grid.0.view())
+    }
+}
+
+impl<T> Index<Point> for SubGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.0[(index.row(), index.col())]
+    }
 }
 
 impl<T: Display> Display for SubGrid<'_, T> {
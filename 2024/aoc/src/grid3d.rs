@@ -89,7 +89,7 @@ impl<T: Clone> Grid3D<T> {
             .filter(|nb| nb.x() < self.height() && nb.y() < self.width() && nb.z() < self.depth())
     }
 
-    pub fn scan(&self, start: Point3D, direction: PointDiff3D) -> Scan3D<T> {
+    pub fn scan(&self, start: Point3D, direction: PointDiff3D) -> Scan3D<'_, T> {
         Scan3D {
             grid: self,
             current: Some(start),
@@ -97,7 +97,7 @@ impl<T: Clone> Grid3D<T> {
         }
     }
 
-    pub fn scan_mut(&mut self, start: Point3D, direction: PointDiff3D) -> ScanMut3D<T> {
+    pub fn scan_mut(&mut self, start: Point3D, direction: PointDiff3D) -> ScanMut3D<'_, T> {
         ScanMut3D {
             grid: self,
             current: Some(start),
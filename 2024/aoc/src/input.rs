@@ -1,18 +1,107 @@
 use std::{
-    env::current_exe,
-    ffi::OsStr,
-    fs::File,
-    io::{BufRead, BufReader, Lines},
-    iter::Map,
-    path::{Component, Path, PathBuf},
+    collections::HashMap,
+    env::{self, current_exe},
+    error::Error,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
     str::FromStr,
+    sync::OnceLock,
 };
 
-use crate::iterator::IteratorExt;
+use thiserror::Error;
 
-pub type FileInput = Map<Lines<BufReader<File>>, fn(std::io::Result<String>) -> String>;
+use crate::{
+    iterator::IteratorExt,
+    scan::{FromCaptures, Pattern},
+};
+
+/// The lines of an input, which can come from a file or from memory.
+pub type LineSource = Box<dyn Iterator<Item = String>>;
+
+// Files are read directly, so the rest of the file can be read at once by `into_text`.
+enum Source {
+    File(BufReader<File>),
+    Lines(LineSource),
+}
+
+pub struct AocInput {
+    source: Source,
+    path: Option<PathBuf>,
+    sample: bool,
+    params: Params,
+    // The number of lines read so far.
+    line: usize,
+}
+
+/// An error parsing a line of the input.
+#[derive(Error, Debug)]
+#[error("{}line {line}: could not parse {text:?}: {source}", location(.path.as_deref()))]
+pub struct ParseError {
+    /// The file the input came from, if it came from a file.
+    pub path: Option<PathBuf>,
+    /// The one-based line number.
+    pub line: usize,
+    /// The text that could not be parsed.
+    pub text: String,
+    #[source]
+    pub source: Box<dyn Error + Send + Sync>,
+}
 
-pub struct AocInput(FileInput);
+impl ParseError {
+    fn parse<T: FromStr>(path: Option<&Path>, line: usize, text: &str) -> Result<T, Self>
+    where
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        text.parse().map_err(|e: T::Err| Self {
+            path: path.map(Path::to_owned),
+            line,
+            text: text.into(),
+            source: e.into(),
+        })
+    }
+}
+
+fn location(path: Option<&Path>) -> String {
+    path.map(|path| format!("{}, ", path.display()))
+        .unwrap_or_default()
+}
+
+// Records parsed from a single string have no line number, and no location.
+fn record_location(path: Option<&Path>, line: Option<usize>) -> String {
+    line.map(|line| format!("{}line {line}: ", location(path)))
+        .unwrap_or_default()
+}
+
+/// The input directory set by [`set_input_dir`].
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Sets the input directory, which takes precedence over the `AOC_INPUT_DIR` environment variable.
+///
+/// # Panics
+///
+/// Panics if the input directory was already set.
+pub fn set_input_dir(dir: impl Into<PathBuf>) {
+    INPUT_DIR
+        .set(dir.into())
+        .expect("the input directory was already set");
+}
+
+/// An input file that wasn't found in any of the input directories.
+#[derive(Error, Debug)]
+#[error("{} not found; tried {}", .name.display(), tried_paths(.tried))]
+pub struct NotFoundError {
+    pub name: PathBuf,
+    pub tried: Vec<PathBuf>,
+}
+
+fn tried_paths(tried: &[PathBuf]) -> String {
+    tried
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
 impl AocInput {
     pub fn get_path(sample: bool) -> PathBuf {
@@ -23,46 +112,156 @@ impl AocInput {
         AocInput::get_path_core(Some(name), sample)
     }
 
-    fn get_path_core(name: Option<&str>, sample: bool) -> PathBuf {
-        let path = current_exe().unwrap();
-        let mut name = name.unwrap_or_else(|| path.file_name().unwrap().to_str().unwrap());
-        if let Some(index) = name.find('-') {
-            name = &name[..index];
+    /// Gets the path of the file holding the recorded answers for the specified day.
+    pub fn get_answers_path(name: &str) -> PathBuf {
+        Self::resolve(Path::new("answers").join(name).with_extension("txt"))
+            .unwrap_or_else(|e| e.tried.into_iter().next().unwrap())
+    }
+
+    /// Finds an input file, returning an error listing every path that was tried if it doesn't
+    /// exist in any of the input directories.
+    pub fn find_path(name: &str, sample: bool) -> Result<PathBuf, NotFoundError> {
+        let mut path = PathBuf::new();
+        if sample {
+            path.push("sample");
         }
 
-        let mut input: PathBuf = path
-            .components()
-            .take_while(|c| *c != Component::Normal(OsStr::new("target")))
-            .collect();
+        path.push(name);
+        path.set_extension("txt");
+        Self::resolve(path)
+    }
 
-        input.push("input");
-        if sample {
-            input.push("sample");
+    /// Gets the directories that are searched for input files, in order: the directory set with
+    /// [`set_input_dir`], the `AOC_INPUT_DIR` environment variable, and the `input` directory of
+    /// the workspace this crate was built in.
+    pub fn input_dirs() -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        dirs.extend(INPUT_DIR.get().cloned());
+        dirs.extend(env::var_os("AOC_INPUT_DIR").map(PathBuf::from));
+        dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name("input"));
+        dirs.dedup();
+        dirs
+    }
+
+    // Gets the path of an existing file, or the path in the first directory if there isn't one,
+    // which is where new files should be created.
+    fn get_path_core(name: Option<&str>, sample: bool) -> PathBuf {
+        let name = name.map_or_else(Self::exe_name, str::to_owned);
+        Self::find_path(&name, sample).unwrap_or_else(|e| e.tried.into_iter().next().unwrap())
+    }
+
+    fn resolve(path: PathBuf) -> Result<PathBuf, NotFoundError> {
+        let tried = Self::input_dirs()
+            .into_iter()
+            .map(|dir| dir.join(&path))
+            .collect::<Vec<_>>();
+
+        match tried.iter().find(|path| path.exists()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(NotFoundError { name: path, tried }),
         }
+    }
 
-        input.push(name);
-        input.set_extension("txt");
-        input
+    // The name of the day, taken from the executable. Test executables have a hash after a '-'.
+    fn exe_name() -> String {
+        let path = current_exe().unwrap();
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        name.split('-').next().unwrap().to_owned()
     }
 
     pub fn from_file(path: impl AsRef<Path>) -> Self {
-        AocInput(
-            BufReader::new(File::open(path).unwrap())
-                .lines()
-                .map(Result::unwrap),
-        )
+        Self::try_from_file(path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Opens an input file, returning an error that includes the path if it can't be opened.
+    pub fn try_from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("could not open {}: {e}", path.display()))
+        })?;
+
+        // Sample inputs are in the sample directory, and may have parameters next to them.
+        let sample = path
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|name| name == "sample");
+
+        Ok(Self {
+            source: Source::File(BufReader::new(file)),
+            path: Some(path.to_owned()),
+            sample,
+            params: Params::load(&path.with_extension("params"))?,
+            line: 0,
+        })
+    }
+
+    /// Creates an input from a string, which is split into lines the same way as a file.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &str) -> Self {
+        Self::from_lines(input.lines())
+    }
+
+    /// Creates an input from the specified lines.
+    pub fn from_lines<S: Into<String>>(lines: impl IntoIterator<Item = S>) -> Self {
+        let lines = lines.into_iter().map(Into::into).into_vec();
+        Self::from_source(Box::new(lines.into_iter()))
+    }
+
+    /// Creates an input that reads its lines from any source. Such an input is treated as a
+    /// sample.
+    pub fn from_source(source: LineSource) -> Self {
+        Self {
+            source: Source::Lines(source),
+            path: None,
+            sample: true,
+            params: Params::default(),
+            line: 0,
+        }
+    }
+
+    /// Gets the path of the file this input was read from, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Checks whether this is a sample input, which is true for files in the `sample` directory
+    /// and for inputs created from memory.
+    pub fn is_sample(&self) -> bool {
+        self.sample
+    }
+
+    /// Gets the puzzle parameters for this input, which are read from a `.params` file next to
+    /// the input file, e.g. `input/sample/day21.params` for `input/sample/day21.txt`.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Sets a parameter, e.g. for a test using an input from memory.
+    pub fn with_param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.0.insert(name.into(), value.to_string());
+        self
     }
 
     pub fn from_input() -> Self {
-        Self::from_file(Self::get_path(false))
+        Self::from_found(Self::find_path(&Self::exe_name(), false))
     }
 
     pub fn from_sample() -> Self {
-        Self::from_file(Self::get_path(true))
+        Self::from_found(Self::find_path(&Self::exe_name(), true))
+    }
+
+    /// Reads one of several sample files for the current day, which are named by adding the suffix
+    /// to the day, e.g. `day10b.txt` for the suffix `"b"`. An empty suffix is the main sample.
+    pub fn from_sample_named(suffix: &str) -> Self {
+        Self::from_found(Self::find_path(&(Self::exe_name() + suffix), true))
+    }
+
+    fn from_found(path: Result<PathBuf, NotFoundError>) -> Self {
+        Self::from_file(path.unwrap_or_else(|e| panic!("{e}")))
     }
 
     pub fn single_line(mut self) -> String {
-        self.0.next().unwrap()
+        self.next().unwrap()
     }
 
     pub fn single_line_parsed<U: FromStr>(self, separator: char) -> Vec<U>
@@ -70,14 +269,121 @@ impl AocInput {
         <U as std::str::FromStr>::Err: std::fmt::Debug,
     {
         // Returns a Vec because using an iterator has issues with the lifetime of the string.
+        let location = self.next_location();
         self.single_line()
             .split(separator)
-            .map(|val| val.parse().unwrap())
+            .map(|val| {
+                val.parse()
+                    .unwrap_or_else(|e| panic!("{location}: could not parse {val:?}: {e:?}"))
+            })
+            .collect()
+    }
+
+    /// Parses each value on a single line separated by `separator`, returning an error for the
+    /// first value that is invalid.
+    pub fn try_single_line_parsed<U: FromStr>(
+        mut self,
+        separator: char,
+    ) -> Result<Vec<U>, ParseError>
+    where
+        U::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let Some(line) = self.next() else {
+            return Err(ParseError {
+                path: self.path().map(Path::to_owned),
+                line: 1,
+                text: String::new(),
+                source: "the input is empty".into(),
+            });
+        };
+
+        line.split(separator)
+            .map(|val| ParseError::parse(self.path(), self.line, val))
             .collect()
     }
 
+    /// Splits the input into blocks separated by blank lines. Each block is an input of its own,
+    /// and line numbers in errors still refer to the whole input.
+    pub fn sections(mut self) -> impl Iterator<Item = AocInput> {
+        std::iter::from_fn(move || self.next_section())
+    }
+
+    /// Splits the input at the first blank line, returning the first block and the rest of the
+    /// input.
+    pub fn two_sections(mut self) -> (AocInput, AocInput) {
+        let first = self
+            .next_section()
+            .unwrap_or_else(|| AocInput::from_lines(Vec::<String>::new()));
+
+        (first, self)
+    }
+
+    fn next_section(&mut self) -> Option<AocInput> {
+        let mut start = self.line;
+        let mut block = Vec::new();
+        for line in self.by_ref() {
+            if !line.is_empty() {
+                block.push(line);
+            } else if block.is_empty() {
+                // Skip extra blank lines between sections.
+                start += 1;
+            } else {
+                break;
+            }
+        }
+
+        (!block.is_empty()).then(|| AocInput {
+            source: Source::Lines(Box::new(block.into_iter())),
+            path: self.path.clone(),
+            sample: self.sample,
+            params: self.params.clone(),
+            line: start,
+        })
+    }
+
+    /// Gets all the integers on each line, ignoring any other text. See [`Ints::ints`].
+    pub fn numbers<T: FromStr>(self) -> impl Iterator<Item = Vec<T>>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        self.map(|line| line.ints())
+    }
+
+    /// Gets all the integers on each line, treating `-` as a separator. See [`Ints::uints`].
+    pub fn unsigned_numbers<T: FromStr>(self) -> impl Iterator<Item = Vec<T>>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        self.map(|line| line.uints())
+    }
+
+    /// Reads the rest of the input into a single buffer. For a file, this reads the file at once
+    /// instead of allocating a string for each line.
+    pub fn into_text(self) -> InputText {
+        let text = match self.source {
+            Source::File(mut reader) => {
+                let mut text = String::new();
+                reader.read_to_string(&mut text).unwrap();
+                text
+            }
+            Source::Lines(lines) => lines.map(|line| line + "\n").collect(),
+        };
+
+        InputText {
+            text,
+            path: self.path,
+            sample: self.sample,
+            params: self.params,
+        }
+    }
+
+    /// Parses each blank-line separated section as a [`Record`].
+    pub fn records(self) -> impl Iterator<Item = Result<Record, RecordError>> {
+        self.sections().map(Record::from_input)
+    }
+
     pub fn into_vec(self) -> Vec<String> {
-        self.0.into_vec()
+        IteratorExt::into_vec(self)
     }
 
     /// Parses the input as the specified type, panicking if anything is invalid.
@@ -85,7 +391,12 @@ impl AocInput {
     where
         <T as std::str::FromStr>::Err: std::fmt::Debug,
     {
-        self.0.map(|line| T::from_str(&line).unwrap())
+        self.parsed_core(|text, path, line| {
+            T::from_str(&text).unwrap_or_else(|e| {
+                let file = location(path);
+                panic!("{file}line {line}: could not parse {text:?}: {e:?}")
+            })
+        })
     }
 
     /// Parses the input as the specified type, panicking if anything is invalid, but blank lines
@@ -94,8 +405,75 @@ impl AocInput {
     where
         <T as std::str::FromStr>::Err: std::fmt::Debug,
     {
-        self.0
-            .map(|line| (!line.is_empty()).then(|| T::from_str(&line).unwrap()))
+        self.parsed_core(|text, path, line| {
+            (!text.is_empty()).then(|| {
+                T::from_str(&text).unwrap_or_else(|e| {
+                    let file = location(path);
+                    panic!("{file}line {line}: could not parse {text:?}: {e:?}")
+                })
+            })
+        })
+    }
+
+    /// Parses the input as the specified type, returning an error with the location of each line
+    /// that is invalid.
+    pub fn try_parsed<T: FromStr>(self) -> impl Iterator<Item = Result<T, ParseError>>
+    where
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.try_parsed_with(str::parse::<T>)
+    }
+
+    /// Parses each line using a function, returning an error with the location of each line that
+    /// is invalid.
+    pub fn try_parsed_with<T, E>(
+        self,
+        f: impl Fn(&str) -> Result<T, E>,
+    ) -> impl Iterator<Item = Result<T, ParseError>>
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let path = self.path.clone();
+        let start = self.line + 1;
+        self.zip(start..).map(move |(text, line)| {
+            f(&text).map_err(|e| ParseError {
+                path: path.clone(),
+                line,
+                text,
+                source: e.into(),
+            })
+        })
+    }
+
+    /// Parses each line using a [`Pattern`] format string, panicking if a line doesn't match.
+    pub fn scanned<T: FromCaptures>(self, pattern: &str) -> impl Iterator<Item = T> {
+        self.try_scanned(pattern)
+            .map(|result| result.unwrap_or_else(|e| panic!("{e}")))
+    }
+
+    /// Parses each line using a [`Pattern`] format string, returning an error with the location
+    /// of each line that doesn't match.
+    pub fn try_scanned<T: FromCaptures>(
+        self,
+        pattern: &str,
+    ) -> impl Iterator<Item = Result<T, ParseError>> {
+        let pattern = Pattern::new(pattern);
+        self.try_parsed_with(move |line| pattern.parse(line))
+    }
+
+    // The location is only formatted if parsing fails, so `f` gets the path and line number.
+    fn parsed_core<T>(
+        self,
+        f: impl Fn(String, Option<&Path>, usize) -> T,
+    ) -> impl Iterator<Item = T> {
+        let path = self.path.clone();
+        let start = self.line + 1;
+        self.zip(start..)
+            .map(move |(text, line)| f(text, path.as_deref(), line))
+    }
+
+    fn next_location(&self) -> String {
+        format!("{}line {}", location(self.path()), self.line + 1)
     }
 }
 
@@ -103,10 +481,357 @@ impl Iterator for AocInput {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let line = match &mut self.source {
+            Source::File(reader) => {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    return None;
+                }
+
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+
+                line
+            }
+            Source::Lines(lines) => lines.next()?,
+        };
+
+        self.line += 1;
+        Some(line)
+    }
+}
+
+/// The whole contents of an input, read at once so lines can be borrowed instead of allocated.
+pub struct InputText {
+    text: String,
+    path: Option<PathBuf>,
+    sample: bool,
+    params: Params,
+}
+
+impl InputText {
+    pub fn is_sample(&self) -> bool {
+        self.sample
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    /// Gets the path of the file this input was read from, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// Gets the lines as bytes, without the line endings.
+    pub fn byte_lines(&self) -> impl Iterator<Item = &[u8]> {
+        self.text.lines().map(str::as_bytes)
+    }
+}
+
+/// An error getting a value from a [`Record`].
+#[derive(Error, Debug)]
+pub enum RecordError {
+    #[error("{}invalid field {text:?}", record_location(.path.as_deref(), *.line))]
+    Syntax {
+        path: Option<PathBuf>,
+        /// The one-based number of the line containing the field.
+        line: Option<usize>,
+        text: String,
+    },
+    #[error("{}missing key {key:?}", record_location(.path.as_deref(), *.line))]
+    Missing {
+        path: Option<PathBuf>,
+        /// The one-based number of the first line of the record.
+        line: Option<usize>,
+        key: String,
+    },
+    #[error(
+        "{}invalid value {value:?} for key {key:?}: {source}",
+        record_location(.path.as_deref(), *.line)
+    )]
+    Value {
+        path: Option<PathBuf>,
+        /// The one-based number of the first line of the record.
+        line: Option<usize>,
+        key: String,
+        value: String,
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+/// A set of named values, such as `{x=787,m=2655}`, `ecl:gry pid:860033327` or a block of
+/// `Starting items: 79, 98` lines.
+#[derive(Clone, Debug, Default)]
+pub struct Record {
+    fields: HashMap<String, String>,
+    path: Option<PathBuf>,
+    // The first line of the record, if it came from an input.
+    line: Option<usize>,
+}
+
+impl Record {
+    /// Parses a line of fields separated by commas or whitespace, where each field is a key and
+    /// value separated by `=` or `:`. Surrounding braces are ignored. A line of the form
+    /// `key: value`, where the value may contain spaces, is a single field.
+    pub fn from_line(line: &str) -> Result<Self, RecordError> {
+        let mut record = Self::default();
+        record.add_line(line, None)?;
+        Ok(record)
+    }
+
+    /// Parses all the lines of an input, which is usually a section, the same way as
+    /// [`Record::from_line`].
+    pub fn from_input(mut input: AocInput) -> Result<Self, RecordError> {
+        let mut record = Self {
+            fields: HashMap::new(),
+            path: input.path().map(Path::to_owned),
+            line: Some(input.line + 1),
+        };
+
+        while let Some(line) = input.next() {
+            record.add_line(&line, Some(input.line))?;
+        }
+
+        Ok(record)
+    }
+
+    fn add_line(&mut self, line: &str, line_number: Option<usize>) -> Result<(), RecordError> {
+        match line.split_once(':') {
+            Some((key, value)) if value.is_empty() || value.starts_with(' ') => {
+                self.fields.insert(key.trim().into(), value.trim().into());
+                Ok(())
+            }
+            _ => self.add_fields(line, line_number),
+        }
+    }
+
+    fn add_fields(&mut self, line: &str, line_number: Option<usize>) -> Result<(), RecordError> {
+        let line = line.trim();
+        let line = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .unwrap_or(line);
+
+        for field in line
+            .split(|ch: char| ch == ',' || ch.is_whitespace())
+            .filter(|field| !field.is_empty())
+        {
+            let Some((key, value)) = field.split_once(['=', ':']) else {
+                return Err(RecordError::Syntax {
+                    path: self.path.clone(),
+                    line: line_number,
+                    text: field.into(),
+                });
+            };
+
+            self.fields.insert(key.into(), value.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.fields.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.keys().map(String::as_str)
+    }
+
+    /// Gets the text of a value, or an error if the key is missing.
+    pub fn get_str(&self, key: &str) -> Result<&str, RecordError> {
+        self.fields
+            .get(key)
+            .map(String::as_str)
+            .ok_or_else(|| self.missing(key))
+    }
+
+    /// Gets a value, or an error if the key is missing or the value is invalid.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T, RecordError>
+    where
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.get_opt(key)?.ok_or_else(|| self.missing(key))
+    }
+
+    /// Gets a value, or `None` if the key is missing.
+    pub fn get_opt<T: FromStr>(&self, key: &str) -> Result<Option<T>, RecordError>
+    where
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let Some(value) = self.fields.get(key) else {
+            return Ok(None);
+        };
+
+        value
+            .parse()
+            .map(Some)
+            .map_err(|e: T::Err| RecordError::Value {
+                path: self.path.clone(),
+                line: self.line,
+                key: key.into(),
+                value: value.clone(),
+                source: e.into(),
+            })
+    }
+
+    fn missing(&self, key: &str) -> RecordError {
+        RecordError::Missing {
+            path: self.path.clone(),
+            line: self.line,
+            key: key.into(),
+        }
     }
 }
 
+/// Parameters that differ between inputs, such as the number of steps to simulate, which are
+/// stored as `name=value` lines. Blank lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (name, value) = line.split_once('=').ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: invalid parameter {line:?}", path.display()),
+                    )
+                })?;
+
+                Ok((name.trim().to_owned(), value.trim().to_owned()))
+            })
+            .collect::<io::Result<_>>()
+            .map(Self)
+    }
+
+    /// Gets a parameter, or `None` if it isn't set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be parsed as `T`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        let value = self.0.get(name)?;
+        Some(
+            value
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value {value:?} for parameter {name}: {e:?}")),
+        )
+    }
+
+    /// Gets a parameter, or the default if it isn't set.
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> T
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        self.get(name).unwrap_or(default)
+    }
+}
+
+/// Extracts the integers from text, ignoring everything else.
+pub trait Ints {
+    /// Gets all the integers in the text. A `-` is a sign if it's followed by a digit and not
+    /// preceded by one, so `x=-1..3` gives `[-1, 3]` but `2-4` gives `[2, 4]`.
+    fn ints<T: FromStr>(&self) -> Vec<T>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug;
+
+    /// Gets all the integers in the text, treating `-` as a separator rather than a sign.
+    fn uints<T: FromStr>(&self) -> Vec<T>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug;
+}
+
+impl Ints for str {
+    fn ints<T: FromStr>(&self) -> Vec<T>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        find_ints(self, true)
+    }
+
+    fn uints<T: FromStr>(&self) -> Vec<T>
+    where
+        <T as std::str::FromStr>::Err: std::fmt::Debug,
+    {
+        find_ints(self, false)
+    }
+}
+
+fn find_ints<T: FromStr>(text: &str, signed: bool) -> Vec<T>
+where
+    <T as std::str::FromStr>::Err: std::fmt::Debug,
+{
+    let bytes = text.as_bytes();
+    let mut result = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let is_sign = signed
+            && bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_digit());
+
+        if !is_sign && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        let value = &text[start..index];
+        result.push(
+            value
+                .parse()
+                .unwrap_or_else(|e| panic!("could not parse {value:?}: {e:?}")),
+        );
+    }
+
+    result
+}
+
 pub trait ParseHex {
     fn parse_hex(&self) -> Vec<u8>;
 }
@@ -122,3 +847,340 @@ impl ParseHex for &str {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::*;
+    use crate::grid::GridBuilder;
+
+    #[test]
+    fn test_from_str() {
+        let input = AocInput::from_str("1 2\n3 4\n");
+        assert_eq!(vec!["1 2", "3 4"], input.into_vec());
+
+        let input = AocInput::from_str("5\r\n-6");
+        assert_eq!(vec![5, -6], input.parsed::<i32>().into_vec());
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let input = AocInput::from_lines(["1,2,3"]);
+        assert_eq!(vec![1, 2, 3], input.single_line_parsed::<u8>(','));
+
+        let lines = vec![String::from("a"), String::new(), String::from("b")];
+        let input = AocInput::from_lines(&lines[..1]);
+        assert_eq!(vec!["a"], input.into_vec());
+
+        let input = AocInput::from_lines(lines);
+        assert_eq!(
+            vec![Some('a'), None, Some('b')],
+            input.parsed_opt::<char>().into_vec()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "line 3: could not parse \"x\"")]
+    fn test_parsed_invalid() {
+        let mut input = AocInput::from_str("header\n1\nx\n3");
+        input.next();
+        input.parsed::<u32>().for_each(drop);
+    }
+
+    #[test]
+    fn test_try_parsed() {
+        let mut input = AocInput::from_str("header\n1\nx\n3");
+        input.next();
+        let result = input.try_parsed::<u32>().into_vec();
+        assert_eq!(1, *result[0].as_ref().unwrap());
+        assert_eq!(3, *result[2].as_ref().unwrap());
+
+        let error = result[1].as_ref().unwrap_err();
+        assert_eq!(None, error.path);
+        assert_eq!(3, error.line);
+        assert_eq!("x", error.text);
+        assert!(error.source.is::<ParseIntError>());
+        assert_eq!(
+            "line 3: could not parse \"x\": invalid digit found in string",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_try_single_line_parsed() {
+        let input = AocInput::from_str("1,2,3");
+        assert_eq!(
+            vec![1, 2, 3],
+            input.try_single_line_parsed::<u8>(',').unwrap()
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1.txt");
+        std::fs::write(&path, "1,-2,3\n").unwrap();
+        let error = AocInput::from_file(&path)
+            .try_single_line_parsed::<u8>(',')
+            .unwrap_err();
+
+        assert_eq!(Some(path.as_path()), error.path.as_deref());
+        assert_eq!(1, error.line);
+        assert_eq!("-2", error.text);
+        assert!(error
+            .to_string()
+            .starts_with(&format!("{}, line 1:", path.display())));
+
+        let error = AocInput::try_from_file(dir.path().join("missing.txt"))
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("missing.txt"));
+
+        let error = AocInput::from_str("")
+            .try_single_line_parsed::<u8>(',')
+            .unwrap_err();
+
+        assert_eq!(1, error.line);
+        assert_eq!(
+            "line 1: could not parse \"\": the input is empty",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = AocInput::from_str("1\n2\n\n\n3\n\nx\n4\n");
+        let mut sections = input.sections();
+        assert_eq!(
+            vec![1, 2],
+            sections.next().unwrap().parsed::<u32>().into_vec()
+        );
+
+        assert_eq!(vec!["3"], sections.next().unwrap().into_vec());
+        let error = sections
+            .next()
+            .unwrap()
+            .try_parsed::<u32>()
+            .find_map(Result::err)
+            .unwrap();
+
+        assert_eq!(7, error.line);
+        assert!(sections.next().is_none());
+    }
+
+    #[test]
+    fn test_two_sections() {
+        let (grid, moves) = AocInput::from_str("#.\n.#\n\n1\n2").two_sections();
+        let grid = GridBuilder::from_input(grid).build();
+        assert_eq!(2, grid.height());
+        assert_eq!(vec![1, 2], moves.parsed::<u8>().into_vec());
+
+        let (first, rest) = AocInput::from_str("").two_sections();
+        assert!(first.into_vec().is_empty());
+        assert!(rest.into_vec().is_empty());
+    }
+
+    #[test]
+    fn test_scanned() {
+        let input = AocInput::from_str("x=1..3,y=-2..5\nx=4..4,y=0..1");
+        assert_eq!(
+            vec![(1, 3, -2, 5), (4, 4, 0, 1)],
+            input
+                .scanned::<(i32, i32, i32, i32)>("x={}..{},y={}..{}")
+                .into_vec()
+        );
+
+        let input = AocInput::from_str("1 -> 2\n3 => 4");
+        let error = input
+            .try_scanned::<(u8, u8)>("{} -> {}")
+            .find_map(Result::err)
+            .unwrap();
+
+        assert_eq!(2, error.line);
+        assert_eq!("3 => 4", error.text);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(vec![-1, 3, 20, 25], "x=-1..3,y=20..25".ints::<i32>());
+        assert_eq!(vec![2, 4, 6, 8], "2-4,6-8".ints::<i32>());
+        assert_eq!(
+            vec![1, 4, 2, 3, 14],
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. 3 and 14"
+                .ints::<u32>()
+        );
+
+        assert_eq!(vec![1, 3], "x=-1..3".uints::<u8>());
+        assert!("no numbers".ints::<i32>().is_empty());
+        assert_eq!(vec![-5], String::from("-5").ints::<i64>());
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input = AocInput::from_str("Time:      7  15   30\nDistance:  9  40  200");
+        assert_eq!(
+            vec![vec![7, 15, 30], vec![9, 40, 200]],
+            input.numbers::<u64>().into_vec()
+        );
+
+        let input = AocInput::from_str("target area: x=20..30, y=-10..-5");
+        assert_eq!(
+            vec![vec![20, 30, 10, 5]],
+            input.unsigned_numbers::<u32>().into_vec()
+        );
+    }
+
+    #[test]
+    fn test_find_path() {
+        let dirs = AocInput::input_dirs();
+        assert_eq!(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name("input"),
+            dirs.last().unwrap()
+        );
+
+        // Every workspace has a sample directory, even before it has any days.
+        let path = AocInput::resolve("sample".into()).unwrap();
+        assert!(path.is_dir() && path.ends_with("sample"));
+
+        let error = AocInput::find_path("day99", true).unwrap_err();
+        assert_eq!(Path::new("sample/day99.txt"), error.name);
+        let error = AocInput::find_path("day99", false).unwrap_err();
+        assert_eq!(Path::new("day99.txt"), error.name);
+        assert_eq!(dirs.len(), error.tried.len());
+        for path in &error.tried {
+            assert!(error.to_string().contains(&path.display().to_string()));
+        }
+
+        // Missing files resolve to the first directory, so they can be created there.
+        assert_eq!(
+            dirs[0].join("sample/day99.txt"),
+            AocInput::get_custom_path("day99", true)
+        );
+    }
+
+    #[test]
+    fn test_into_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1.txt");
+        std::fs::write(&path, "header\r\n\r\n#.\r\n.#\r\n").unwrap();
+        let mut input = AocInput::from_file(&path);
+        assert_eq!("header", input.next().unwrap());
+        assert_eq!("", input.next().unwrap());
+
+        let text = input.into_text();
+        assert_eq!(Some(path.as_path()), text.path());
+        assert_eq!("#.\r\n.#\r\n", text.as_str());
+        assert_eq!(vec!["#.", ".#"], text.lines().into_vec());
+        assert_eq!(
+            vec![b"#.".as_slice(), b".#".as_slice()],
+            text.byte_lines().into_vec()
+        );
+
+        let text = AocInput::from_str("a\nb").into_text();
+        assert_eq!("a\nb\n", text.as_str());
+    }
+
+    #[test]
+    fn test_params() {
+        let dir = tempfile::tempdir().unwrap();
+        let sample_dir = dir.path().join("sample");
+        fs::create_dir(&sample_dir).unwrap();
+        fs::write(sample_dir.join("day21.txt"), "S..\n").unwrap();
+        fs::write(
+            sample_dir.join("day21.params"),
+            "# The number of steps\nsteps = 6\n\nname=x\n",
+        )
+        .unwrap();
+
+        fs::write(dir.path().join("day21.txt"), "S..\n").unwrap();
+
+        let input = AocInput::from_file(sample_dir.join("day21.txt"));
+        assert!(input.is_sample());
+        assert_eq!(Some(6), input.params().get::<usize>("steps"));
+        assert_eq!(Some(String::from("x")), input.params().get("name"));
+        assert_eq!(None, input.params().get::<usize>("missing"));
+
+        let text = input.into_text();
+        assert!(text.is_sample());
+        assert_eq!(6, text.params().get_or("steps", 64));
+
+        let input = AocInput::from_file(dir.path().join("day21.txt"));
+        assert!(!input.is_sample());
+        assert_eq!(64, input.params().get_or("steps", 64));
+
+        let input = AocInput::from_str("S..").with_param("steps", 10);
+        assert!(input.is_sample());
+        assert_eq!(Some(10u32), input.params().get("steps"));
+
+        fs::write(sample_dir.join("day21.params"), "steps\n").unwrap();
+        let error = AocInput::try_from_file(sample_dir.join("day21.txt"))
+            .err()
+            .unwrap();
+
+        assert!(error.to_string().contains("invalid parameter"));
+    }
+
+    #[test]
+    fn test_record_from_line() {
+        let record = Record::from_line("{x=787,m=2655,a=1222,s=2876}").unwrap();
+        assert_eq!(4, record.len());
+        assert_eq!(787, record.get::<u32>("x").unwrap());
+        assert_eq!(2876, record.get::<u32>("s").unwrap());
+        assert_eq!(None, record.get_opt::<u32>("y").unwrap());
+        assert_eq!(
+            "missing key \"y\"",
+            record.get::<u32>("y").unwrap_err().to_string()
+        );
+
+        let record = Record::from_line("ecl:gry pid:860033327 hcl:#fffffd").unwrap();
+        assert_eq!("#fffffd", record.get_str("hcl").unwrap());
+        assert!(matches!(
+            record.get::<u8>("pid"),
+            Err(RecordError::Value { .. })
+        ));
+
+        assert!(matches!(
+            Record::from_line("a=1,b"),
+            Err(RecordError::Syntax { .. })
+        ));
+
+        let record = Record::from_line("  Starting items: 79, 98").unwrap();
+        assert_eq!(1, record.len());
+        assert_eq!("79, 98", record.get_str("Starting items").unwrap());
+        let record = Record::from_line("Monkey 0:").unwrap();
+        assert_eq!("", record.get_str("Monkey 0").unwrap());
+    }
+
+    #[test]
+    fn test_records() {
+        let input = AocInput::from_str(
+            "ecl:gry pid:860033327\nbyr:1937 iyr:2017\n\n\
+            Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23",
+        );
+
+        let records = input.records().map(Result::unwrap).into_vec();
+        assert_eq!(2, records.len());
+        assert_eq!(1937, records[0].get::<u32>("byr").unwrap());
+        assert!(records[0].contains("ecl"));
+        assert_eq!("", records[1].get_str("Monkey 0").unwrap());
+        assert_eq!("79, 98", records[1].get_str("Starting items").unwrap());
+        assert_eq!("new = old * 19", records[1].get_str("Operation").unwrap());
+
+        let error = records[1].get_str("If true").unwrap_err();
+        assert!(matches!(error, RecordError::Missing { line: Some(4), .. }));
+        assert_eq!("line 4: missing key \"If true\"", error.to_string());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day4.txt");
+        std::fs::write(&path, "byr:1937\n\niyr:2017 eyr\n").unwrap();
+        let error = AocInput::from_file(&path)
+            .records()
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+
+        assert!(matches!(error, RecordError::Syntax { line: Some(3), .. }));
+        assert_eq!(
+            format!("{}, line 3: invalid field \"eyr\"", path.display()),
+            error.to_string()
+        );
+    }
+}
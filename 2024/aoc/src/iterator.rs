@@ -58,14 +58,14 @@ impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for TakeWhilePeek<'_, I,
 }
 
 pub trait PeekableExt<I: Iterator> {
-    fn take_while_peek<P>(&mut self, predicate: P) -> TakeWhilePeek<I, P>
+    fn take_while_peek<P>(&mut self, predicate: P) -> TakeWhilePeek<'_, I, P>
     where
         Self: Sized,
         P: FnMut(&I::Item) -> bool;
 }
 
 impl<I: Iterator> PeekableExt<I> for Peekable<I> {
-    fn take_while_peek<P>(&mut self, predicate: P) -> TakeWhilePeek<I, P>
+    fn take_while_peek<P>(&mut self, predicate: P) -> TakeWhilePeek<'_, I, P>
     where
         Self: Sized,
         P: FnMut(&<I as Iterator>::Item) -> bool,
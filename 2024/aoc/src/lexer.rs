@@ -0,0 +1,243 @@
+//! A simple lexer that walks through a string byte by byte, for inputs that are too irregular
+//! for `scan` but don't need a regular expression.
+
+use std::{error::Error, str::FromStr};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LexError {
+    #[error("expected {expected} at position {position}")]
+    Expected { expected: String, position: usize },
+    #[error("invalid number {text:?} at position {position}: {source}")]
+    Number {
+        text: String,
+        position: usize,
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl LexError {
+    /// The position in the text where the error occurred.
+    pub fn position(&self) -> usize {
+        match self {
+            LexError::Expected { position, .. } | LexError::Number { position, .. } => *position,
+        }
+    }
+}
+
+/// A saved position that the lexer can go back to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint(usize);
+
+pub struct Lexer<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    /// The byte offset of the next byte to read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position >= self.text.len()
+    }
+
+    /// The text that hasn't been read yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lexer stopped in the middle of a multi-byte character.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn rest_bytes(&self) -> &'a [u8] {
+        &self.text.as_bytes()[self.position.min(self.text.len())..]
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    /// Reads the next byte.
+    pub fn advance(&mut self) -> Option<u8> {
+        let result = self.peek()?;
+        self.position += 1;
+        Some(result)
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.position)
+    }
+
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.position = checkpoint.0;
+    }
+
+    /// Runs `f`, and goes back to the current position if it fails.
+    pub fn attempt<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, LexError>,
+    ) -> Result<T, LexError> {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+        if result.is_err() {
+            self.restore(checkpoint);
+        }
+
+        result
+    }
+
+    /// Reads the literal if the text continues with it, and returns whether it did.
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        if self.rest_bytes().starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads the literal, or returns an error without moving if the text doesn't continue with
+    /// it.
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), LexError> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.expected(format!("{literal:?}")))
+        }
+    }
+
+    /// Finds the first of the literals that the text continues with, without reading it, and
+    /// returns its index.
+    pub fn peek_match(&self, literals: &[&str]) -> Option<usize> {
+        let rest = self.rest_bytes();
+        literals
+            .iter()
+            .position(|literal| rest.starts_with(literal.as_bytes()))
+    }
+
+    /// Reads the first of the literals that the text continues with, and returns its index.
+    pub fn try_match(&mut self, literals: &[&str]) -> Option<usize> {
+        let index = self.peek_match(literals)?;
+        self.position += literals[index].len();
+        Some(index)
+    }
+
+    /// Reads an unsigned integer, which must have at least one digit.
+    pub fn read_uint<T: FromStr>(&mut self) -> Result<T, LexError>
+    where
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let start = self.position;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        if self.position == start {
+            return Err(self.expected("a number".into()));
+        }
+
+        let text = &self.text[start..self.position];
+        text.parse().map_err(|e: T::Err| {
+            self.position = start;
+            LexError::Number {
+                text: text.into(),
+                position: start,
+                source: e.into(),
+            }
+        })
+    }
+
+    fn expected(&self, expected: String) -> LexError {
+        LexError::Expected {
+            expected,
+            position: self.position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexer() {
+        let mut lexer = Lexer::new("mul(12,345)x");
+        lexer.expect_literal("mul(").unwrap();
+        assert_eq!(12, lexer.read_uint::<u32>().unwrap());
+        assert!(lexer.expect_literal(")").is_err());
+        assert_eq!(6, lexer.position());
+        lexer.expect_literal(",").unwrap();
+        assert_eq!(345, lexer.read_uint::<u32>().unwrap());
+        assert!(lexer.try_literal(")"));
+        assert_eq!("x", lexer.rest());
+        assert_eq!(Some(b'x'), lexer.advance());
+        assert!(lexer.is_at_end());
+        assert_eq!(None, lexer.advance());
+
+        let error = Lexer::new("abc").read_uint::<u32>().unwrap_err();
+        assert_eq!(0, error.position());
+        assert_eq!("expected a number at position 0", error.to_string());
+
+        let mut lexer = Lexer::new("999");
+        assert!(matches!(
+            lexer.read_uint::<u8>(),
+            Err(LexError::Number { position: 0, .. })
+        ));
+
+        assert_eq!(0, lexer.position());
+    }
+
+    #[test]
+    fn test_match() {
+        let mut lexer = Lexer::new("eightwo");
+        let digits = ["one", "two", "eight"];
+        assert_eq!(Some(2), lexer.peek_match(&digits));
+        assert_eq!(0, lexer.position());
+
+        // Overlapping matches are found by advancing one byte at a time.
+        let mut found = Vec::new();
+        while !lexer.is_at_end() {
+            found.extend(lexer.peek_match(&digits));
+            lexer.advance();
+        }
+
+        assert_eq!(vec![2, 1], found);
+
+        let mut lexer = Lexer::new("é do()");
+        lexer.advance();
+        assert_eq!(None, lexer.try_match(&["do()"]));
+
+        let mut lexer = Lexer::new("don't()");
+        assert_eq!(Some(1), lexer.try_match(&["do()", "don't()"]));
+        assert!(lexer.is_at_end());
+    }
+
+    #[test]
+    fn test_attempt() {
+        let mut lexer = Lexer::new("mul(1,x)");
+        let checkpoint = lexer.checkpoint();
+        let result = lexer.attempt(|lexer| {
+            lexer.expect_literal("mul(")?;
+            let left = lexer.read_uint::<u32>()?;
+            lexer.expect_literal(",")?;
+            Ok(left * lexer.read_uint::<u32>()?)
+        });
+
+        assert_eq!(6, result.unwrap_err().position());
+        assert_eq!(checkpoint, lexer.checkpoint());
+
+        lexer.advance();
+        lexer.restore(checkpoint);
+        assert_eq!(0, lexer.position());
+    }
+}
@@ -1,18 +1,29 @@
+// Lets the code generated by the derive macros refer to `::aoc` inside this crate's tests.
+#[cfg(test)]
+extern crate self as aoc;
+
 pub mod bitfield;
 pub mod bitreader;
 pub mod circular_list;
 pub mod dijkstra;
+#[cfg(feature = "fetch")]
+pub mod fetch;
 pub mod graph;
 pub mod grid;
 pub mod grid3d;
 pub mod input;
 pub mod iterator;
+pub mod lexer;
 pub mod nested_list;
+pub mod scan;
 pub mod slice;
+pub mod solution;
 pub mod tree;
 
 use std::collections::HashMap;
 
+pub use aoc_derive::{aoc_tests, AocParse};
+
 use num::Integer;
 
 pub trait Lcm<T> {
@@ -0,0 +1,373 @@
+//! Parses lines using a simple format string, where each `{}` captures a value.
+//!
+//! Whitespace in the pattern matches one or more whitespace characters in the text, and captured
+//! values are trimmed, so `"{}, {} @ {}"` also matches `"19,  13 @ -2"`.
+//!
+//! ```
+//! # use aoc::scan;
+//! let (x1, x2) = scan!("x={}..{}", "x=-1..3" => i64, i64).unwrap();
+//! assert_eq!((-1, 3), (x1, x2));
+//! ```
+
+use std::{error::Error, str::FromStr};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScanError {
+    #[error("expected {expected:?} at position {position} of {text:?}")]
+    Mismatch {
+        text: String,
+        expected: String,
+        position: usize,
+    },
+    #[error("expected {expected} values, but the pattern has {actual} placeholders")]
+    CaptureCount { expected: usize, actual: usize },
+    #[error("could not parse value {index} ({value:?}): {source}")]
+    Value {
+        index: usize,
+        value: String,
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+    #[error("could not parse {field} ({value:?}): {source}")]
+    Field {
+        field: &'static str,
+        value: String,
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+/// A pattern consisting of literal text and `{}` placeholders.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    // The literal text around the placeholders; there is one more literal than placeholders.
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    /// Creates a pattern from a format string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two placeholders are adjacent, because there is no way to tell where one value
+    /// ends and the next begins.
+    pub fn new(format: &str) -> Self {
+        let literals = format.split("{}").map(str::to_owned).collect::<Vec<_>>();
+        assert!(
+            literals.len() < 3
+                || literals[1..literals.len() - 1]
+                    .iter()
+                    .all(|l| !l.is_empty()),
+            "pattern {format:?} has adjacent placeholders"
+        );
+
+        Self { literals }
+    }
+
+    /// The number of placeholders in the pattern.
+    pub fn len(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Matches the text against the pattern, returning the text of each capture.
+    pub fn captures<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, ScanError> {
+        let mismatch = |literal: &str, position| ScanError::Mismatch {
+            text: text.into(),
+            expected: literal.into(),
+            position,
+        };
+
+        let (first, rest) = self.literals.split_first().unwrap();
+        let mut position = match_literal(text, 0, first).ok_or_else(|| mismatch(first, 0))?;
+
+        let mut captures = Vec::with_capacity(self.len());
+        for (index, literal) in rest.iter().enumerate() {
+            let is_last = index == rest.len() - 1;
+            let (start, end) = if is_last && literal.is_empty() {
+                (text.len(), text.len())
+            } else if is_last {
+                // The last literal must match at the end of the text.
+                (position..=text.len())
+                    .filter(|&start| text.is_char_boundary(start))
+                    .find_map(|start| {
+                        let end = match_literal(text, start, literal)?;
+                        text[end..].trim().is_empty().then_some((start, text.len()))
+                    })
+                    .ok_or_else(|| mismatch(literal, position))?
+            } else {
+                find_literal(text, position, literal).ok_or_else(|| mismatch(literal, position))?
+            };
+
+            captures.push(text[position..start].trim());
+            position = end;
+        }
+
+        if self.is_empty() && !text[position..].trim().is_empty() {
+            return Err(mismatch("", position));
+        }
+
+        Ok(captures)
+    }
+
+    /// Matches the text against the pattern, and parses the captures into a tuple.
+    pub fn parse<T: FromCaptures>(&self, text: &str) -> Result<T, ScanError> {
+        if T::COUNT != self.len() {
+            return Err(ScanError::CaptureCount {
+                expected: T::COUNT,
+                actual: self.len(),
+            });
+        }
+
+        T::from_captures(&self.captures(text)?)
+    }
+}
+
+/// A tuple of values that can be parsed from the captures of a [`Pattern`].
+pub trait FromCaptures: Sized {
+    const COUNT: usize;
+
+    fn from_captures(captures: &[&str]) -> Result<Self, ScanError>;
+}
+
+/// Parses a single capture, including its index in the error.
+pub fn parse_capture<T: FromStr>(index: usize, value: &str) -> Result<T, ScanError>
+where
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    value.parse().map_err(|e: T::Err| ScanError::Value {
+        index,
+        value: value.into(),
+        source: e.into(),
+    })
+}
+
+/// Parses the capture for a named field; used by `#[derive(AocParse)]`.
+pub fn parse_field<T: FromStr>(field: &'static str, value: &str) -> Result<T, ScanError>
+where
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    value.parse().map_err(|e: T::Err| ScanError::Field {
+        field,
+        value: value.into(),
+        source: e.into(),
+    })
+}
+
+/// Parses the capture for a named field that holds a list of values; used by
+/// `#[derive(AocParse)]`. An empty capture is an empty list.
+pub fn parse_list_field<C, T>(
+    field: &'static str,
+    value: &str,
+    separator: &str,
+) -> Result<C, ScanError>
+where
+    C: FromIterator<T>,
+    T: FromStr,
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    if value.is_empty() {
+        return Ok(C::from_iter(std::iter::empty()));
+    }
+
+    value
+        .split(separator)
+        .map(|item| parse_field(field, item.trim()))
+        .collect()
+}
+
+macro_rules! impl_from_captures {
+    ($count:literal; $($t:ident $index:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+)
+        where
+            $($t::Err: Into<Box<dyn Error + Send + Sync>>),+
+        {
+            const COUNT: usize = $count;
+
+            fn from_captures(captures: &[&str]) -> Result<Self, ScanError> {
+                Ok(($(parse_capture::<$t>($index, captures[$index])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1; A 0);
+impl_from_captures!(2; A 0, B 1);
+impl_from_captures!(3; A 0, B 1, C 2);
+impl_from_captures!(4; A 0, B 1, C 2, D 3);
+impl_from_captures!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_captures!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_captures!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_captures!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Parses text using a format string, returning a tuple with a value of each type, or a
+/// [`ScanError`].
+#[macro_export]
+macro_rules! scan {
+    ($format:expr, $text:expr => $($t:ty),+ $(,)?) => {
+        $crate::scan::Pattern::new($format).parse::<($($t,)+)>($text)
+    };
+}
+
+// Returns the position after the literal if it matches at `start`.
+fn match_literal(text: &str, start: usize, literal: &str) -> Option<usize> {
+    let mut text_chars = text[start..].char_indices().peekable();
+    let mut end = start;
+    let mut literal_chars = literal.chars().peekable();
+    while let Some(ch) = literal_chars.next() {
+        if ch.is_whitespace() {
+            while literal_chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+            text_chars.peek().filter(|(_, ch)| ch.is_whitespace())?;
+            while let Some((index, ch)) = text_chars.next_if(|(_, ch)| ch.is_whitespace()) {
+                end = start + index + ch.len_utf8();
+            }
+        } else {
+            let (index, text_ch) = text_chars.next()?;
+            if text_ch != ch {
+                return None;
+            }
+
+            end = start + index + ch.len_utf8();
+        }
+    }
+
+    Some(end)
+}
+
+// Finds the first position at or after `start` where the literal matches, and returns its start
+// and end.
+fn find_literal(text: &str, start: usize, literal: &str) -> Option<(usize, usize)> {
+    (start..=text.len())
+        .filter(|&index| text.is_char_boundary(index))
+        .find_map(|index| Some((index, match_literal(text, index, literal)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captures() {
+        let pattern = Pattern::new("x={}..{},y={}..{}");
+        assert_eq!(4, pattern.len());
+        assert_eq!(
+            vec!["-1", "3", "20", "25"],
+            pattern.captures("x=-1..3,y=20..25").unwrap()
+        );
+
+        let pattern = Pattern::new("{}, {}, {} @ {}, {}, {}");
+        assert_eq!(
+            vec!["19", "13", "30", "-2", "1", "-2"],
+            pattern.captures("19, 13, 30 @ -2,  1, -2").unwrap()
+        );
+
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        assert_eq!(
+            vec!["2", "18", "-2", "15"],
+            pattern
+                .captures("Sensor at x=2, y=18: closest beacon is at x=-2, y=15")
+                .unwrap()
+        );
+
+        let pattern = Pattern::new("{} {}");
+        assert_eq!(vec!["ab", "cd  e"], pattern.captures("ab   cd  e").unwrap());
+
+        let pattern = Pattern::new("move {} from {} to {}");
+        assert!(matches!(
+            pattern.captures("move 1 from 2"),
+            Err(ScanError::Mismatch { position: 12, .. })
+        ));
+
+        assert!(matches!(
+            pattern.captures("mov 1 from 2 to 3"),
+            Err(ScanError::Mismatch { position: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            (-1, 3, 20, 25),
+            scan!("x={}..{},y={}..{}", "x=-1..3,y=20..25" => i64, i64, i64, i64).unwrap()
+        );
+
+        assert_eq!(
+            (String::from("AA"), 0u32),
+            scan!("Valve {} has flow rate={};", "Valve AA has flow rate=0;" => String, u32)
+                .unwrap()
+        );
+
+        let error = scan!("{} @ {}", "1 @ x" => u32, u32).unwrap_err();
+        assert!(matches!(error, ScanError::Value { index: 1, .. }));
+        assert_eq!(
+            "could not parse value 1 (\"x\"): invalid digit found in string",
+            error.to_string()
+        );
+
+        assert!(matches!(
+            scan!("{} @ {}", "1 @ 2" => u32),
+            Err(ScanError::CaptureCount {
+                expected: 1,
+                actual: 2
+            })
+        ));
+    }
+
+    #[derive(crate::AocParse, Debug, PartialEq, Eq)]
+    #[aoc(format = "Valve {name} has flow rate={rate}; tunnels lead to valves {tunnels:, }")]
+    struct Valve {
+        name: String,
+        rate: u32,
+        tunnels: Vec<String>,
+    }
+
+    #[test]
+    fn test_derive() {
+        assert_eq!(
+            Valve {
+                name: "AA".into(),
+                rate: 13,
+                tunnels: vec!["DD".into(), "II".into(), "BB".into()]
+            },
+            "Valve AA has flow rate=13; tunnels lead to valves DD, II, BB"
+                .parse()
+                .unwrap()
+        );
+
+        let error = "Valve AA has flow rate=x; tunnels lead to valves DD"
+            .parse::<Valve>()
+            .unwrap_err();
+
+        assert!(matches!(error, ScanError::Field { field: "rate", .. }));
+        assert_eq!(
+            "could not parse rate (\"x\"): invalid digit found in string",
+            error.to_string()
+        );
+
+        assert!(matches!(
+            "Valve AA has flow rate=1".parse::<Valve>(),
+            Err(ScanError::Mismatch { .. })
+        ));
+
+        let valves = crate::input::AocInput::from_str(
+            "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+            Valve BB has flow rate=2; tunnels lead to valves ",
+        )
+        .parsed::<Valve>()
+        .map(|valve| (valve.name, valve.tunnels.len()))
+        .collect::<Vec<_>>();
+
+        assert_eq!(vec![("AA".into(), 1), ("BB".into(), 0)], valves);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_adjacent_placeholders() {
+        Pattern::new("{}{}");
+    }
+}
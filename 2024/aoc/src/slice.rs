@@ -83,7 +83,8 @@ pub trait SliceExt<T> {
 
     fn get_two_mut(&mut self, index1: usize, index2: usize) -> (&mut T, &mut T);
 
-    fn split_inclusive_start<P: FnMut(&T) -> bool>(&self, pred: P) -> SplitInclusiveStart<T, P>;
+    fn split_inclusive_start<P: FnMut(&T) -> bool>(&self, pred: P)
+        -> SplitInclusiveStart<'_, T, P>;
 }
 
 impl<T> SliceExt<T> for [T] {
@@ -109,7 +110,10 @@ impl<T> SliceExt<T> for [T] {
     }
 
     /// Splits a slice, including the separator at the start of the subslices.
-    fn split_inclusive_start<P: FnMut(&T) -> bool>(&self, pred: P) -> SplitInclusiveStart<T, P> {
+    fn split_inclusive_start<P: FnMut(&T) -> bool>(
+        &self,
+        pred: P,
+    ) -> SplitInclusiveStart<'_, T, P> {
         SplitInclusiveStart {
             source: self,
             current: 0,
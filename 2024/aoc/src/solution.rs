@@ -0,0 +1,74 @@
+use std::{any::Any, fmt::Display};
+
+use crate::input::AocInput;
+
+/// Implemented by each day to provide the answers to both parts of the puzzle.
+///
+/// The input is parsed once by `parse`, and the result is shared by both parts.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: AocInput) -> Self::Parsed;
+    fn part1(input: &Self::Parsed) -> impl Display;
+    fn part2(input: &Self::Parsed) -> impl Display;
+}
+
+/// The result of `Solution::parse` for a `Day`, with its type erased.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// A type-erased solution for a single day, so solutions for different days can be stored
+/// together.
+#[derive(Clone, Copy)]
+pub struct Day {
+    number: u32,
+    parse: fn(AocInput) -> ParsedInput,
+    part1: fn(&ParsedInput) -> String,
+    part2: fn(&ParsedInput) -> String,
+}
+
+impl Day {
+    pub fn new<S>(number: u32) -> Self
+    where
+        S: Solution,
+        S::Parsed: 'static,
+    {
+        Self {
+            number,
+            parse: |input| ParsedInput(Box::new(S::parse(input))),
+            part1: |input| S::part1(Self::downcast::<S>(input)).to_string(),
+            part2: |input| S::part2(Self::downcast::<S>(input)).to_string(),
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn input_name(&self) -> String {
+        format!("day{}", self.number)
+    }
+
+    pub fn parse(&self, input: AocInput) -> ParsedInput {
+        (self.parse)(input)
+    }
+
+    /// Runs the specified part on input returned by `parse`, returning the answer.
+    pub fn run(&self, part: u32, input: &ParsedInput) -> String {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => panic!("invalid part {part}"),
+        }
+    }
+
+    fn downcast<S>(input: &ParsedInput) -> &S::Parsed
+    where
+        S: Solution,
+        S::Parsed: 'static,
+    {
+        input
+            .0
+            .downcast_ref()
+            .expect("parsed input belongs to a different day")
+    }
+}
@@ -0,0 +1,51 @@
+// https://adventofcode.com/2024/day/1
+
+use std::fmt::Display;
+
+use aoc::{input::AocInput, solution::Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<isize>, Vec<isize>);
+
+    fn parse(input: AocInput) -> Self::Parsed {
+        input
+            .map(|line| {
+                let (left, right) = line.split_once("   ").unwrap();
+                (
+                    left.parse::<isize>().unwrap(),
+                    right.parse::<isize>().unwrap(),
+                )
+            })
+            .unzip()
+    }
+
+    // Get the distance between each number in the two lists after sorting them.
+    fn part1((list1, list2): &Self::Parsed) -> impl Display {
+        let mut list1 = list1.clone();
+        let mut list2 = list2.clone();
+        list1.sort();
+        list2.sort();
+        list1
+            .into_iter()
+            .zip(list2)
+            .map(|(left, right)| (right - left).unsigned_abs())
+            .sum::<usize>()
+    }
+
+    // Multiply each number in list 1 with how often it occurs in list 2.
+    fn part2((list1, list2): &Self::Parsed) -> impl Display {
+        list1
+            .iter()
+            .map(|&num1| num1 as usize * list2.iter().filter(|&&num2| num1 == num2).count())
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::aoc_tests!(Day1, part1: [("", 11)], part2: [("", 31)]);
+}
@@ -1,36 +1,34 @@
 // https://adventofcode.com/2024/day/2
 
-use std::{cmp::Ordering, str::FromStr};
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
-use aoc::input::AocInput;
+use aoc::{input::AocInput, solution::Solution};
 
-fn main() {
-    println!("Part 1: {}", part1(AocInput::from_input()));
-    println!("Part 2: {}", part2(AocInput::from_input()));
-}
+pub struct Day2;
 
-// How many reports are safe (increasing or decreasing with a difference no greater than 3).
-fn part1(input: AocInput) -> usize {
-    input
-        .filter(|line| {
-            let report: Report = line.parse().unwrap();
-            report.is_safe()
-        })
-        .count()
-}
+impl Solution for Day2 {
+    type Parsed = Vec<Report>;
 
-// Same, but with the "dampener" which allows removing any one level from an unsafe report.
-fn part2(input: AocInput) -> usize {
-    input
-        .filter(|line| {
-            let report: Report = line.parse().unwrap();
-            report.is_safe_with_dampener()
-        })
-        .count()
+    fn parse(input: AocInput) -> Self::Parsed {
+        input.parsed().collect()
+    }
+
+    // How many reports are safe (increasing or decreasing with a difference no greater than 3).
+    fn part1(reports: &Self::Parsed) -> impl Display {
+        reports.iter().filter(|report| report.is_safe()).count()
+    }
+
+    // Same, but with the "dampener" which allows removing any one level from an unsafe report.
+    fn part2(reports: &Self::Parsed) -> impl Display {
+        reports
+            .iter()
+            .filter(|report| report.is_safe_with_dampener())
+            .count()
+    }
 }
 
 #[derive(Clone)]
-struct Report {
+pub struct Report {
     levels: Vec<isize>,
 }
 
@@ -108,13 +106,5 @@ enum Order {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(2, part1(AocInput::from_sample()));
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(4, part2(AocInput::from_sample()));
-    }
+    aoc::aoc_tests!(Day2, part1: [("", 2)], part2: [("", 4)]);
 }
@@ -0,0 +1,87 @@
+// https://adventofcode.com/2024/day/3
+
+use std::fmt::Display;
+
+use aoc::{input::AocInput, lexer::Lexer, solution::Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: AocInput) -> Self::Parsed {
+        input.into_vec()
+    }
+
+    // Sum the result of all the mul() instructions.
+    fn part1(input: &Self::Parsed) -> impl Display {
+        input
+            .iter()
+            .flat_map(|line| instructions(line))
+            .map(|instruction| match instruction {
+                Instruction::Mul(left, right) => left * right,
+                _ => 0,
+            })
+            .sum::<usize>()
+    }
+
+    // Same, but also use do() and don't() instructions to toggle processing mul() on/off.
+    fn part2(input: &Self::Parsed) -> impl Display {
+        let mut sum = 0;
+        let mut active = true;
+        for instruction in input.iter().flat_map(|line| instructions(line)) {
+            match instruction {
+                Instruction::Do => active = true,
+                Instruction::Dont => active = false,
+                Instruction::Mul(left, right) => {
+                    if active {
+                        sum += left * right
+                    }
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+// Finds the valid instructions in a corrupted line, skipping everything else.
+fn instructions(line: &str) -> Vec<Instruction> {
+    let mut lexer = Lexer::new(line);
+    let mut result = Vec::new();
+    while !lexer.is_at_end() {
+        match lexer.try_match(&["mul(", "do()", "don't()"]) {
+            Some(0) => {
+                let mul = lexer.attempt(|lexer| {
+                    let left = lexer.read_uint()?;
+                    lexer.expect_literal(",")?;
+                    let right = lexer.read_uint()?;
+                    lexer.expect_literal(")")?;
+                    Ok(Instruction::Mul(left, right))
+                });
+
+                result.extend(mul.ok());
+            }
+            Some(1) => result.push(Instruction::Do),
+            Some(2) => result.push(Instruction::Dont),
+            _ => {
+                lexer.advance();
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::aoc_tests!(Day3, part1: [("", 161)], part2: [("b", 48)]);
+}
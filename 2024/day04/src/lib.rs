@@ -1,33 +1,41 @@
 // https://adventofcode.com/2024/day/4
 
-use std::char;
+use std::{char, fmt::Display};
 
 use aoc::{
     grid::{Grid, GridBuilder, Point, PointDiff},
     input::AocInput,
+    solution::Solution,
 };
 
-fn main() {
-    println!("Part 1: {}", part1(AocInput::from_input()));
-    println!("Part 2: {}", part2(AocInput::from_input()));
-}
+pub struct Day4;
 
-// Find all occurrences of XMAS in the grid, in all directions.
-fn part1(input: AocInput) -> usize {
-    let search = WordSearch::from_input(input);
-    search.find_word("XMAS")
-}
+impl Solution for Day4 {
+    type Parsed = WordSearch;
+
+    fn parse(input: AocInput) -> Self::Parsed {
+        WordSearch::from_input(input)
+    }
+
+    // Find all occurrences of XMAS in the grid, in all directions.
+    fn part1(search: &Self::Parsed) -> impl Display {
+        search.find_word("XMAS")
+    }
 
-fn part2(input: AocInput) -> usize {
-    let search = WordSearch::from_input(input);
-    search.find_x_shaped(&['M', 'A', 'S'])
+    fn part2(search: &Self::Parsed) -> impl Display {
+        search.find_x_shaped(&['M', 'A', 'S'])
+    }
 }
 
-struct WordSearch(Grid<char>);
+pub struct WordSearch(Grid<char>);
 
 impl WordSearch {
     fn from_input(input: AocInput) -> Self {
-        Self(GridBuilder::from_input(input).chars().build())
+        Self(
+            GridBuilder::from_bytes(input.into_text().as_bytes())
+                .chars()
+                .build(),
+        )
     }
 
     fn find_word(&self, word: &str) -> usize {
@@ -88,13 +96,5 @@ fn find_occurrences(mut input: &str, pattern: &str) -> usize {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(18, part1(AocInput::from_sample()));
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(9, part2(AocInput::from_sample()));
-    }
+    aoc::aoc_tests!(Day4, part1: [("", 18)], part2: [("", 9)]);
 }
@@ -0,0 +1,50 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use anyhow::Result;
+
+/// Reads the answers for both parts, one per line. A missing or empty line means there is no
+/// recorded answer for that part, and a missing file means there are no answers at all.
+pub fn read_answers(path: &Path) -> Result<Option<[Option<String>; 2]>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut lines = contents.lines().map(|line| {
+        Some(line.trim())
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+    });
+
+    Ok(Some([lines.next().flatten(), lines.next().flatten()]))
+}
+
+/// Writes the answers for both parts, leaving a line empty if a part has no answer.
+pub fn write_answers(path: &Path, answers: &[Option<String>; 2]) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    let [part1, part2] = answers
+        .each_ref()
+        .map(|answer| answer.as_deref().unwrap_or_default());
+    fs::write(path, format!("{part1}\n{part2}\n"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers/day1.txt");
+        assert_eq!(None, read_answers(&path).unwrap());
+
+        write_answers(&path, &[None, Some("42".into())]).unwrap();
+        assert_eq!("\n42\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            Some([None, Some("42".into())]),
+            read_answers(&path).unwrap()
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use aoc::input::AocInput;
+use clap::Args;
+
+use crate::Selection;
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Use the sample input instead of the real input.
+    #[arg(short, long)]
+    sample: bool,
+
+    /// How many times to run each phase.
+    #[arg(short = 'n', long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Print the results as JSON instead of a table.
+    #[arg(long)]
+    json: bool,
+}
+
+/// The timings of each phase of a single day.
+struct DayTimings {
+    day: u32,
+    phases: [Timings; 3],
+}
+
+struct Timings {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+impl Timings {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+pub fn run(args: &BenchArgs) -> Result<()> {
+    if !args.json {
+        println!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+            "Day", "Phase", "Min", "Median", "Mean"
+        );
+    }
+
+    let mut results = Vec::new();
+    for (day, path) in args.selection.days(args.sample)? {
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+        let mut input = None;
+        for _ in 0..args.iterations {
+            let file = AocInput::from_file(&path);
+            let start = Instant::now();
+            let parsed = day.parse(file);
+            samples[0].push(start.elapsed());
+            input = Some(parsed);
+        }
+
+        let input = input.unwrap();
+        for part in 1..=2 {
+            for _ in 0..args.iterations {
+                let start = Instant::now();
+                black_box(day.run(part, &input));
+                samples[part as usize].push(start.elapsed());
+            }
+        }
+
+        let timings = DayTimings {
+            day: day.number(),
+            phases: samples.map(Timings::new),
+        };
+
+        // Print each day as it finishes, since slow days can take a while.
+        if !args.json {
+            print_table_rows(&timings);
+        }
+
+        results.push(timings);
+    }
+
+    if args.json {
+        print_json(&results);
+    }
+
+    Ok(())
+}
+
+fn print_table_rows(timings: &DayTimings) {
+    for (phase, t) in PHASES.iter().zip(&timings.phases) {
+        println!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+            timings.day,
+            phase,
+            format!("{:.2?}", t.min),
+            format!("{:.2?}", t.median),
+            format!("{:.2?}", t.mean)
+        );
+    }
+}
+
+fn print_json(results: &[DayTimings]) {
+    println!("[");
+    for (index, timings) in results.iter().enumerate() {
+        let phases: Vec<_> = PHASES
+            .iter()
+            .zip(&timings.phases)
+            .map(|(phase, t)| {
+                format!(
+                    "\"{phase}\": {{\"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+                    t.min.as_nanos(),
+                    t.median.as_nanos(),
+                    t.mean.as_nanos()
+                )
+            })
+            .collect();
+
+        let separator = if index + 1 < results.len() { "," } else { "" };
+        println!(
+            "  {{\"day\": {}, {}}}{separator}",
+            timings.day,
+            phases.join(", ")
+        );
+    }
+
+    println!("]");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings() {
+        let t = Timings::new(
+            [5, 1, 3, 7]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+
+        assert_eq!(Duration::from_millis(1), t.min);
+        assert_eq!(Duration::from_millis(4), t.median);
+        assert_eq!(Duration::from_millis(4), t.mean);
+
+        let t = Timings::new([9, 2, 4].into_iter().map(Duration::from_millis).collect());
+        assert_eq!(Duration::from_millis(2), t.min);
+        assert_eq!(Duration::from_millis(4), t.median);
+        assert_eq!(Duration::from_millis(5), t.mean);
+    }
+}
@@ -0,0 +1,12 @@
+// The list of days is updated by the `new` command.
+use aoc::solution::Day;
+
+/// Gets all the days that have a solution, in order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day1::Day1>(1),
+        Day::new::<day2::Day2>(2),
+        Day::new::<day3::Day3>(3),
+        Day::new::<day4::Day4>(4),
+    ]
+}
@@ -0,0 +1,183 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc::{
+    fetch::{decode_entities, strip_tags},
+    input::AocInput,
+};
+use clap::Args;
+
+#[derive(Args)]
+pub struct ExtractArgs {
+    /// The saved puzzle page.
+    page: PathBuf,
+
+    /// The day of the puzzle; read from the page's title if not specified.
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Overwrite sample files that already have contents.
+    #[arg(short, long)]
+    force: bool,
+}
+
+/// The examples and highlighted values found on a puzzle page.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Puzzle {
+    day: Option<u32>,
+    samples: Vec<String>,
+    // The emphasized values in each part's description, which include the sample answers.
+    emphasized: Vec<Vec<String>>,
+}
+
+pub fn run(args: &ExtractArgs) -> Result<()> {
+    let html = fs::read_to_string(&args.page)
+        .with_context(|| format!("could not read {}", args.page.display()))?;
+
+    let puzzle = parse_puzzle(&html);
+    let Some(day) = args.day.or(puzzle.day) else {
+        bail!("could not determine the day from the page; use --day");
+    };
+
+    if puzzle.samples.is_empty() {
+        bail!("no examples found in {}", args.page.display());
+    }
+
+    // Name all the files first, so nothing is written if there are too many.
+    let names = (0..puzzle.samples.len())
+        .map(|index| Ok(format!("day{day}{}", sample_suffix(index)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (name, sample) in names.iter().zip(&puzzle.samples) {
+        let path = AocInput::get_custom_path(name, true);
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !existing.is_empty() && !args.force {
+            eprintln!("Skipping {}: it already exists.", path.display());
+            continue;
+        }
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, sample)?;
+        let lines = sample.lines().count();
+        let first = sample.lines().next().unwrap_or_default();
+        println!("Wrote {} ({lines} lines): {first}", path.display());
+    }
+
+    for (part, values) in (1..).zip(&puzzle.emphasized) {
+        if let Some(last) = values.last() {
+            println!(
+                "Part {part}: probably {last} (emphasized: {})",
+                values.join(", ")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The first sample has no suffix, followed by "b", "c", etc.
+fn sample_suffix(index: usize) -> Result<String> {
+    match index {
+        0 => Ok(String::new()),
+        1..=25 => Ok(char::from(b'a' + index as u8).to_string()),
+        _ => bail!("too many examples; only 26 sample files can be named"),
+    }
+}
+
+fn parse_puzzle(html: &str) -> Puzzle {
+    let mut puzzle = Puzzle {
+        day: find_between(html, "--- Day ", ":").and_then(|day| day.trim().parse().ok()),
+        ..Default::default()
+    };
+
+    // Each part of the puzzle has its own article.
+    for article in html.split("<article").skip(1) {
+        let article = article.split("</article>").next().unwrap();
+        puzzle.samples.extend(
+            find_all(article, "<pre><code>", "</code></pre>")
+                .map(|block| decode_entities(&strip_tags(block))),
+        );
+
+        // Examples can have emphasized parts too, so leave them out when looking for answers.
+        let prose = article
+            .split("<pre>")
+            .enumerate()
+            .map(|(index, part)| match part.split_once("</pre>") {
+                Some((_, rest)) if index > 0 => rest,
+                _ => part,
+            })
+            .collect::<String>();
+
+        puzzle.emphasized.push(
+            find_all(&prose, "<code><em>", "</em></code>")
+                .map(|value| decode_entities(&strip_tags(value)))
+                .collect(),
+        );
+    }
+
+    puzzle
+}
+
+fn find_between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    Some(rest.split_once(end)?.0)
+}
+
+fn find_all<'a>(
+    mut text: &'a str,
+    start: &'a str,
+    end: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    std::iter::from_fn(move || {
+        let (_, rest) = text.split_once(start)?;
+        let (value, rest) = rest.split_once(end)?;
+        text = rest;
+        Some(value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Ceres Search ---</h2>
+<p>For example:</p>
+<pre><code>..X...
+.SAMX.
+</code></pre>
+<p>Take a look at the little Elf's word search. How many times does <code>XMAS</code> appear? In
+this example, it appears <code><em>18</em></code> times.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code><em>M</em>.S
+.A.
+M&lt;S &amp; &#35;
+</code></pre>
+<p>An X-MAS appears <code><em>9</em></code> times.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse_puzzle(PAGE);
+        assert_eq!(
+            Puzzle {
+                day: Some(4),
+                samples: vec!["..X...\n.SAMX.\n".into(), "M.S\n.A.\nM<S & #\n".into()],
+                emphasized: vec![vec!["18".into()], vec!["9".into()]],
+            },
+            puzzle
+        );
+    }
+
+    #[test]
+    fn test_sample_suffix() {
+        assert_eq!("", sample_suffix(0).unwrap());
+        assert_eq!("b", sample_suffix(1).unwrap());
+        assert_eq!("c", sample_suffix(2).unwrap());
+        assert_eq!("z", sample_suffix(25).unwrap());
+        assert!(sample_suffix(26).is_err());
+        assert!(sample_suffix(200).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+mod answers;
+mod bench;
+mod days;
+mod extract;
+mod new;
+mod submit;
+mod verify;
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use aoc::{
+    input::{self, AocInput},
+    solution::Day,
+};
+use clap::{Args, Parser, Subcommand};
+
+const YEAR: &str = env!("CARGO_PKG_VERSION_MAJOR");
+
+/// Runs the solutions for this year's puzzles.
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// The directory containing the input files. Defaults to the `AOC_INPUT_DIR` environment
+    /// variable, or the `input` directory of this workspace.
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solutions and reports how long each phase takes.
+    Bench(bench::BenchArgs),
+    /// Checks the answers for the real input against the recorded answers.
+    Verify(verify::VerifyArgs),
+    /// Creates a new day from the template, and downloads its input.
+    New(new::NewArgs),
+    /// Extracts the examples from a saved puzzle page into sample input files.
+    Extract(extract::ExtractArgs),
+    /// Submits the answer for one part of a day to the website.
+    Submit(submit::SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Only run this part, instead of both parts.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Use the sample input instead of the real input.
+    #[arg(short, long)]
+    sample: bool,
+}
+
+/// Selects which days to run.
+#[derive(Args)]
+pub struct Selection {
+    /// The year to run; must match the year of this workspace.
+    year: Option<u32>,
+
+    /// Only run this day, instead of all days.
+    #[arg(short, long)]
+    day: Option<u32>,
+}
+
+impl Selection {
+    /// Gets the selected days, along with the path of their input.
+    ///
+    /// Days whose input is missing are reported and skipped.
+    pub fn days(&self, sample: bool) -> Result<Vec<(Day, PathBuf)>> {
+        if let Some(year) = self.year {
+            if year.to_string() != YEAR {
+                bail!("this runner is for {YEAR}, not {year}");
+            }
+        }
+
+        let mut days = days::all();
+        if let Some(number) = self.day {
+            days.retain(|day| day.number() == number);
+            if days.is_empty() {
+                bail!("day {number} has no solution");
+            }
+        }
+
+        Ok(days
+            .into_iter()
+            .filter_map(|day| match AocInput::find_path(&day.input_name(), sample) {
+                Ok(path) => Some((day, path)),
+                Err(e) => {
+                    eprintln!("Input file {e}.");
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if let Some(dir) = cli.input_dir {
+        input::set_input_dir(dir);
+    }
+
+    match cli.command {
+        Some(Command::Bench(args)) => bench::run(&args),
+        Some(Command::Verify(args)) => verify::run(&args),
+        Some(Command::New(args)) => new::run(&args),
+        Some(Command::Extract(args)) => extract::run(&args),
+        Some(Command::Submit(args)) => submit::run(&args),
+        None => run(&cli.run),
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let parts = args.part.map_or(1..=2, |part| part..=part);
+    for (day, path) in args.selection.days(args.sample)? {
+        println!("Day {}", day.number());
+        let input = day.parse(AocInput::from_file(&path));
+        for part in parts.clone() {
+            println!("Part {part}: {}", day.run(part, &input));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,534 @@
+use std::{
+    fs::{self, File},
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc::fetch::{self, Client};
+use clap::Args;
+
+use crate::YEAR;
+
+/// The widest list of days that rustfmt puts on a single line.
+const ARRAY_WIDTH: usize = 60;
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// The year to create the day in; the year is created if it doesn't exist yet.
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// The day to create; defaults to the day after the last existing day.
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// The session cookie used to download the input. If not specified, it's read from the
+    /// `AOC_SESSION` environment variable or the configuration file.
+    #[arg(long)]
+    session: Option<String>,
+
+    /// The address of the Advent of Code website.
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl NewArgs {
+    /// The client used to download the input, or `None` if there is no session.
+    fn client(&self) -> Option<Client> {
+        let session = self.session.clone().or_else(fetch::find_session)?;
+        Some(Client::new(session).base_url(&self.base_url))
+    }
+}
+
+pub fn run(args: &NewArgs) -> Result<()> {
+    let year = match args.year {
+        Some(year) => year,
+        None => YEAR.parse()?,
+    };
+
+    let day = new_day(&repo_root(), year, args.day, args.client())?;
+    println!("Created day {day}");
+    Ok(())
+}
+
+/// Creates a day, and its year if that doesn't exist yet, and downloads the input if there is a
+/// client to do so. Returns the day number.
+fn new_day(root: &Path, year: u32, day: Option<u32>, client: Option<Client>) -> Result<u32> {
+    if let Some(day) = day {
+        check_day(year, day)?;
+    }
+
+    let base_path = root.join(year.to_string());
+    if !base_path.exists() {
+        create_year(root, YEAR, year)?;
+        println!("Created year {year}");
+    }
+
+    let day = create_day(root, year, day)?;
+    let Some(mut client) = client else {
+        eprintln!("Warning: no session provided, so the input was not downloaded.");
+        return Ok(day);
+    };
+
+    let input_path = base_path.join(format!("input/day{day}.txt"));
+    match client.fetch_input(year, day, &input_path) {
+        Ok(true) => {}
+        Ok(false) => eprintln!("Warning: {} already exists.", input_path.display()),
+        Err(e) => eprintln!("Warning: failed to download the input: {e}"),
+    }
+
+    Ok(day)
+}
+
+/// The repository root, which contains the templates and a directory for each year.
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .unwrap()
+        .to_owned()
+}
+
+/// Creates a new year workspace, copying the `aoc` library and runner from the source year.
+fn create_year(root: &Path, source: &str, year: u32) -> Result<()> {
+    let source = root.join(source);
+    let target = root.join(year.to_string());
+    fs::create_dir(&target)?;
+    fs::create_dir_all(target.join("input/sample"))?;
+    copy_dir(&source.join("aoc"), &target.join("aoc"), &|contents| {
+        contents
+    })?;
+
+    let manifest = fs::read_to_string(source.join("Cargo.toml"))?;
+    let manifest = manifest
+        .lines()
+        .map(|line| {
+            if line.starts_with("version = ") {
+                format!("version = \"{year}.0.0\"\n")
+            } else {
+                format!("{line}\n")
+            }
+        })
+        .collect::<String>();
+
+    create_file(&target.join("Cargo.toml"), &manifest)?;
+    create_file(
+        &target.join("README.md"),
+        &format!("# Advent of Code {year}\n\n[Event homepage](https://adventofcode.com/{year})\n"),
+    )?;
+
+    // The runner starts out without any days.
+    let runner = target.join("runner");
+    fs::create_dir_all(runner.join("src"))?;
+    let manifest = fs::read_to_string(source.join("runner/Cargo.toml"))?;
+    let manifest = manifest
+        .lines()
+        .filter(|line| !line.starts_with("day"))
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+
+    create_file(&runner.join("Cargo.toml"), &manifest)?;
+    for entry in fs::read_dir(source.join("runner/src"))? {
+        let entry = entry?;
+        if entry.file_name() != "days.rs" {
+            fs::copy(entry.path(), runner.join("src").join(entry.file_name()))?;
+        }
+    }
+
+    write_days(&runner.join("src/days.rs"), &[])
+}
+
+/// Creates a day from the template, and registers it with the runner. Returns the day number.
+fn create_day(root: &Path, year: u32, day: Option<u32>) -> Result<u32> {
+    let base_path = root.join(year.to_string());
+    if !base_path.is_dir() {
+        bail!("year {year} doesn't exist");
+    }
+
+    // The template uses the `Solution` trait and `aoc_tests!`, which older libraries don't have,
+    // so days for those years are binaries with their own `main`.
+    let solution =
+        base_path.join("aoc/src/solution.rs").exists() && base_path.join("aoc/derive").is_dir();
+    let template = if solution { "template" } else { "template-bin" };
+
+    let day = match day {
+        Some(day) => day,
+        None => next_day(&base_path)?,
+    };
+
+    check_day(year, day)?;
+
+    let dest = base_path.join(format!("day{day:02}"));
+    if dest.exists() {
+        bail!("{} already exists", dest.display());
+    }
+
+    let year_str = year.to_string();
+    let day_str = day.to_string();
+    copy_dir(&root.join(template), &dest, &|contents| {
+        contents
+            .replace("%DAY%", &day_str)
+            .replace("%YEAR%", &year_str)
+    })?;
+
+    let runner = base_path.join("runner");
+    if solution && runner.exists() {
+        register_day(&runner, day)?;
+    }
+
+    let sample_path = base_path.join(format!("input/sample/day{day}.txt"));
+    fs::create_dir_all(sample_path.parent().unwrap())?;
+    match File::create_new(&sample_path) {
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            eprintln!("Warning: {} already exists.", sample_path.display())
+        }
+        result => {
+            result?;
+        }
+    }
+
+    Ok(day)
+}
+
+fn check_day(year: u32, day: u32) -> Result<()> {
+    if !(1..=fetch::days_in_year(year)).contains(&day) {
+        bail!("day {day} of {year} is not a valid puzzle");
+    }
+
+    Ok(())
+}
+
+/// Finds the day after the highest existing day.
+fn next_day(base_path: &Path) -> Result<u32> {
+    let mut max = 0;
+    for entry in fs::read_dir(base_path)? {
+        let name = entry?.file_name();
+        if let Some(day) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse().ok())
+        {
+            max = max.max(day);
+        }
+    }
+
+    Ok(max + 1)
+}
+
+/// Adds the day as a dependency of the runner, and to the list of days it can run.
+fn register_day(runner: &Path, day: u32) -> Result<()> {
+    let manifest_path = runner.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let Some(start) = lines.iter().position(|line| *line == "[dependencies]") else {
+        bail!("{} has no dependencies section", manifest_path.display());
+    };
+
+    // Add the day at the end of the dependencies section, before any blank lines.
+    let mut index = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with('['))
+        .map_or(lines.len(), |index| start + 1 + index);
+
+    while lines[index - 1].trim().is_empty() {
+        index -= 1;
+    }
+
+    let dependency = format!("day{day} = {{ path = \"../day{day:02}\" }}");
+    lines.insert(index, &dependency);
+    fs::write(&manifest_path, lines.join("\n") + "\n")?;
+
+    let days_path = runner.join("src/days.rs");
+    let mut days = fs::read_to_string(&days_path)?
+        .split("Day::new::<day")
+        .skip(1)
+        .filter_map(|entry| entry.split_once("::")?.0.parse().ok())
+        .collect::<Vec<u32>>();
+
+    days.push(day);
+    days.sort();
+    days.dedup();
+    write_days(&days_path, &days)
+}
+
+fn write_days(path: &Path, days: &[u32]) -> Result<()> {
+    let entries = days
+        .iter()
+        .map(|day| format!("Day::new::<day{day}::Day{day}>({day})"))
+        .collect::<Vec<_>>();
+
+    // Lay out the list the way rustfmt does, which keeps it on one line if it's short enough.
+    let list = entries.join(", ");
+    let list = if list.len() <= ARRAY_WIDTH {
+        format!("vec![{list}]")
+    } else {
+        let lines = entries
+            .iter()
+            .map(|entry| format!("        {entry},\n"))
+            .collect::<String>();
+
+        format!("vec![\n{lines}    ]")
+    };
+
+    fs::write(
+        path,
+        format!(
+            "// The list of days is updated by the `new` command.\n\
+            use aoc::solution::Day;\n\
+            \n\
+            /// Gets all the days that have a solution, in order.\n\
+            pub fn all() -> Vec<Day> {{\n    {list}\n}}\n"
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Recursively copies a directory, transforming the contents of each file. Fails if any of the
+/// files already exist.
+fn copy_dir(source: &Path, dest: &Path, transform: &dyn Fn(String) -> String) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let path = entry.path();
+        let dest_path = dest.join(entry.file_name());
+        if path.is_dir() {
+            if entry.file_name() != "target" {
+                copy_dir(&path, &dest_path, transform)?;
+            }
+        } else {
+            let contents = fs::read_to_string(&path)?;
+            create_file(&dest_path, &transform(contents))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn create_file(path: &Path, contents: &str) -> Result<()> {
+    let mut file =
+        File::create_new(path).with_context(|| format!("could not create {}", path.display()))?;
+
+    io::Write::write_all(&mut file, contents.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: NewArgs,
+    }
+
+    fn create_test_root() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path();
+        for (template, main) in [("template", "lib.rs"), ("template-bin", "main.rs")] {
+            let template = path.join(template);
+            fs::create_dir_all(template.join("src")).unwrap();
+            fs::write(template.join("Cargo.toml"), "name = \"day%DAY%\"\n").unwrap();
+            fs::write(template.join("src").join(main), "// %YEAR% day %DAY%\n").unwrap();
+        }
+
+        fs::create_dir_all(path.join("2024/aoc/derive")).unwrap();
+        fs::create_dir_all(path.join("2024/aoc/src")).unwrap();
+        fs::write(path.join("2024/aoc/src/solution.rs"), "").unwrap();
+        fs::write(
+            path.join("2024/Cargo.toml"),
+            "[workspace.package]\nversion = \"2024.0.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(path.join("2024/day01")).unwrap();
+        fs::create_dir_all(path.join("2024/day03")).unwrap();
+        fs::create_dir_all(path.join("2024/runner/src")).unwrap();
+        fs::write(path.join("2024/runner/src/main.rs"), "mod days;\n").unwrap();
+        fs::write(
+            path.join("2024/runner/Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day01\" }\nday3 = { path = \"../day03\" }\n\n\
+            [dev-dependencies]\ntempfile = \"3\"\n",
+        )
+        .unwrap();
+
+        write_days(&path.join("2024/runner/src/days.rs"), &[1, 3]).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = create_test_root();
+        let path = root.path();
+        assert_eq!(4, create_day(path, 2024, None).unwrap());
+        assert_eq!(
+            "name = \"day4\"\n",
+            fs::read_to_string(path.join("2024/day04/Cargo.toml")).unwrap()
+        );
+        assert_eq!(
+            "// 2024 day 4\n",
+            fs::read_to_string(path.join("2024/day04/src/lib.rs")).unwrap()
+        );
+        assert!(path.join("2024/input/sample/day4.txt").exists());
+
+        // Days created out of order are still listed in order.
+        assert_eq!(2, create_day(path, 2024, Some(2)).unwrap());
+        let manifest = fs::read_to_string(path.join("2024/runner/Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "day4 = { path = \"../day04\" }\nday2 = { path = \"../day02\" }\n\n[dev-dependencies]"
+        ));
+
+        let days = fs::read_to_string(path.join("2024/runner/src/days.rs")).unwrap();
+        let entries = days
+            .lines()
+            .filter(|line| line.contains("Day::new"))
+            .map(str::trim)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            [
+                "Day::new::<day1::Day1>(1),",
+                "Day::new::<day2::Day2>(2),",
+                "Day::new::<day3::Day3>(3),",
+                "Day::new::<day4::Day4>(4),"
+            ],
+            entries.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_create_day_existing() {
+        let root = create_test_root();
+        assert!(create_day(root.path(), 2024, Some(3)).is_err());
+        assert!(create_day(root.path(), 2023, None).is_err());
+    }
+
+    #[test]
+    fn test_create_day_invalid() {
+        let root = create_test_root();
+        let path = root.path();
+        assert!(create_day(path, 2024, Some(0)).is_err());
+        assert!(create_day(path, 2024, Some(26)).is_err());
+        assert!(!path.join("2024/day26").exists());
+        assert!(!path.join("2024/input/sample/day26.txt").exists());
+
+        for day in 5..=25 {
+            create_day(path, 2024, Some(day)).unwrap();
+        }
+
+        // There is no day after the last day of the event.
+        assert!(create_day(path, 2024, None).is_err());
+    }
+
+    #[test]
+    fn test_create_year() {
+        let root = create_test_root();
+        let path = root.path();
+        create_year(path, "2024", 2025).unwrap();
+        assert_eq!(
+            "[workspace.package]\nversion = \"2025.0.0\"\n",
+            fs::read_to_string(path.join("2025/Cargo.toml")).unwrap()
+        );
+
+        assert!(path.join("2025/aoc/src/solution.rs").exists());
+        assert!(path.join("2025/input/sample").is_dir());
+        assert_eq!(
+            "mod days;\n",
+            fs::read_to_string(path.join("2025/runner/src/main.rs")).unwrap()
+        );
+
+        // The runner starts out without any days.
+        assert_eq!(
+            "[dependencies]\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+            fs::read_to_string(path.join("2025/runner/Cargo.toml")).unwrap()
+        );
+
+        let days = fs::read_to_string(path.join("2025/runner/src/days.rs")).unwrap();
+        assert!(days.contains("    vec![]\n"));
+        assert!(create_year(path, "2024", 2025).is_err());
+
+        assert_eq!(1, create_day(path, 2025, None).unwrap());
+        assert!(create_day(path, 2025, Some(13)).is_err());
+        let days = fs::read_to_string(path.join("2025/runner/src/days.rs")).unwrap();
+        assert!(days.contains("    vec![Day::new::<day1::Day1>(1)]\n"));
+        assert!(fs::read_to_string(path.join("2025/runner/Cargo.toml"))
+            .unwrap()
+            .starts_with("[dependencies]\nday1 = { path = \"../day01\" }\n\n"));
+    }
+
+    #[test]
+    fn test_create_day_binary() {
+        // A year whose library is too old for the template gets a day with its own main.
+        let root = create_test_root();
+        let path = root.path();
+        fs::create_dir_all(path.join("2022/aoc/src")).unwrap();
+        fs::create_dir_all(path.join("2022/day1")).unwrap();
+        assert_eq!(2, create_day(path, 2022, None).unwrap());
+        assert_eq!(
+            "// 2022 day 2\n",
+            fs::read_to_string(path.join("2022/day02/src/main.rs")).unwrap()
+        );
+
+        assert!(!path.join("2022/day02/src/lib.rs").exists());
+        assert!(path.join("2022/input/sample/day2.txt").exists());
+    }
+
+    #[test]
+    fn test_download_input() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+
+                request.push(line.trim().to_owned());
+            }
+
+            let body = "1 2\n3 4\n";
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        let cli = Cli::parse_from([
+            "new",
+            "--day=5",
+            "--session=secret",
+            "--base-url",
+            &base_url,
+        ]);
+        let client = cli.args.client().unwrap().last_request_path(None);
+        let root = create_test_root();
+        let path = root.path();
+        assert_eq!(5, new_day(path, 2024, cli.args.day, Some(client)).unwrap());
+        assert_eq!(
+            "1 2\n3 4\n",
+            fs::read_to_string(path.join("2024/input/day5.txt")).unwrap()
+        );
+
+        let request = server.join().unwrap();
+        assert_eq!("GET /2024/day/5/input HTTP/1.1", request[0]);
+        assert!(request.iter().any(|line| line == "Cookie: session=secret"));
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Result};
+use aoc::{
+    fetch::{self, Client, Verdict},
+    input::AocInput,
+};
+use clap::Args;
+
+use crate::{
+    answers::{read_answers, write_answers},
+    Selection, YEAR,
+};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// The day to submit the answer for.
+    #[arg(short, long)]
+    day: u32,
+
+    /// The part to submit the answer for.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+
+    /// The session cookie used to submit the answer. If not specified, it's read from the
+    /// `AOC_SESSION` environment variable or the configuration file.
+    #[arg(long)]
+    session: Option<String>,
+
+    /// The address of the Advent of Code website.
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+/// The wrong answers submitted for a day, and when the next answer may be submitted.
+///
+/// This is stored in `input/answers/dayN_submissions.txt`, with one line per wrong answer in the
+/// form `<part> <wrong|high|low> <answer>`, and a line `wait <unix time>` for the cooldown.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct History {
+    wrong: Vec<(u32, Verdict, String)>,
+    wait_until: Option<SystemTime>,
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut history = Self::default();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let mut fields = line.splitn(3, ' ');
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some("wait"), Some(time), None) => {
+                    let time = UNIX_EPOCH + Duration::from_secs(time.parse()?);
+                    history.wait_until = history.wait_until.max(Some(time));
+                }
+                (Some(part), Some(kind), Some(answer)) => {
+                    let verdict = match kind {
+                        "wrong" => Verdict::Incorrect,
+                        "high" => Verdict::TooHigh,
+                        "low" => Verdict::TooLow,
+                        _ => bail!("invalid line in {}: {line}", path.display()),
+                    };
+
+                    history
+                        .wrong
+                        .push((part.parse()?, verdict, answer.to_owned()));
+                }
+                _ => bail!("invalid line in {}: {line}", path.display()),
+            }
+        }
+
+        Ok(history)
+    }
+
+    /// Checks whether earlier wrong answers already rule out an answer, and returns the reason if
+    /// so. The bounds only apply to integer answers.
+    fn ruled_out(&self, part: u32, answer: &str) -> Option<String> {
+        let value = answer.parse::<i128>().ok();
+        self.wrong
+            .iter()
+            .filter(|(wrong_part, ..)| *wrong_part == part)
+            .find_map(|(_, verdict, wrong)| {
+                if wrong == answer {
+                    return Some(format!("{answer} was already submitted and is wrong"));
+                }
+
+                let bound = wrong.parse::<i128>().ok()?;
+                match verdict {
+                    Verdict::TooHigh if value? >= bound => Some(format!(
+                        "{answer} is too high, because {bound} was too high"
+                    )),
+                    Verdict::TooLow if value? <= bound => {
+                        Some(format!("{answer} is too low, because {bound} was too low"))
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    fn append(path: &Path, line: &str) -> Result<()> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")?;
+        Ok(())
+    }
+}
+
+pub fn run(args: &SubmitArgs) -> Result<()> {
+    let selection = Selection {
+        year: None,
+        day: Some(args.day),
+    };
+
+    let Some((day, path)) = selection.days(false)?.pop() else {
+        bail!("day {} has no input", args.day);
+    };
+
+    let input = day.parse(AocInput::from_file(&path));
+    let answer = day.run(args.part, &input);
+    println!("Day {} part {}: {answer}", args.day, args.part);
+
+    let answers_path = AocInput::get_answers_path(&day.input_name());
+    let mut answers = read_answers(&answers_path)?.unwrap_or_default();
+    let index = args.part as usize - 1;
+    match &answers[index] {
+        Some(correct) if *correct == answer => {
+            println!("This is the recorded answer, so it was not submitted.");
+            return Ok(());
+        }
+        Some(correct) => bail!(
+            "part {} already has a recorded answer: {correct}",
+            args.part
+        ),
+        None => {}
+    }
+
+    let history_path = AocInput::get_answers_path(&format!("{}_submissions", day.input_name()));
+    let history = History::load(&history_path)?;
+    if let Some(reason) = history.ruled_out(args.part, &answer) {
+        bail!("not submitting: {reason}");
+    }
+
+    if let Some(wait) = history
+        .wait_until
+        .and_then(|time| time.duration_since(SystemTime::now()).ok())
+    {
+        bail!(
+            "wait {}s before submitting another answer",
+            wait.as_secs() + 1
+        );
+    }
+
+    let Some(session) = args.session.clone().or_else(fetch::find_session) else {
+        bail!("no session provided");
+    };
+
+    let mut client = Client::new(session).base_url(&args.base_url);
+    let response = client.submit_answer(YEAR.parse()?, args.day, args.part, &answer)?;
+    if let Some(wait) = response.wait {
+        let until = SystemTime::now() + wait;
+        let until = until.duration_since(UNIX_EPOCH)?.as_secs();
+        History::append(&history_path, &format!("wait {until}"))?;
+    }
+
+    let kind = match &response.verdict {
+        Verdict::Correct => {
+            answers[index] = Some(answer);
+            write_answers(&answers_path, &answers)?;
+            println!("That's the right answer!");
+            return Ok(());
+        }
+        Verdict::TooHigh => "high",
+        Verdict::TooLow => "low",
+        Verdict::Incorrect => "wrong",
+        Verdict::TooRecent => bail!(
+            "an answer was submitted too recently; wait {}s",
+            response.wait.unwrap_or_default().as_secs()
+        ),
+        Verdict::WrongLevel => {
+            bail!("part {} is already solved, or not available yet", args.part)
+        }
+        Verdict::Unknown(text) => bail!("unexpected response: {text}"),
+    };
+
+    History::append(&history_path, &format!("{} {kind} {answer}", args.part))?;
+    match response.verdict {
+        Verdict::TooHigh => bail!("that's not the right answer; it's too high"),
+        Verdict::TooLow => bail!("that's not the right answer; it's too low"),
+        _ => bail!("that's not the right answer"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1_submissions.txt");
+        assert_eq!(History::default(), History::load(&path).unwrap());
+
+        History::append(&path, "1 high 100").unwrap();
+        History::append(&path, "1 low 10").unwrap();
+        History::append(&path, "1 wrong 50").unwrap();
+        History::append(&path, "2 wrong abc").unwrap();
+        History::append(&path, "wait 1700000000").unwrap();
+        let history = History::load(&path).unwrap();
+        assert_eq!(
+            Some(UNIX_EPOCH + Duration::from_secs(1700000000)),
+            history.wait_until
+        );
+
+        assert!(history.ruled_out(1, "100").is_some());
+        assert!(history.ruled_out(1, "150").is_some());
+        assert!(history.ruled_out(1, "10").is_some());
+        assert!(history.ruled_out(1, "-5").is_some());
+        assert!(history.ruled_out(1, "50").is_some());
+        assert!(history.ruled_out(1, "42").is_none());
+        assert!(history.ruled_out(1, "abc").is_none());
+        assert!(history.ruled_out(2, "abc").is_some());
+        assert!(history.ruled_out(2, "150").is_none());
+    }
+}
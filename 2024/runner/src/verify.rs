@@ -0,0 +1,174 @@
+use std::{any::Any, panic, path::Path};
+
+use anyhow::{bail, Result};
+use aoc::{input::AocInput, solution::Day};
+use clap::Args;
+
+use crate::{
+    answers::{read_answers, write_answers},
+    Selection,
+};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Record the current answers for days that don't have recorded answers yet.
+    #[arg(long)]
+    record: bool,
+}
+
+pub fn run(args: &VerifyArgs) -> Result<()> {
+    let mut failed = 0;
+    for (day, path) in args.selection.days(false)? {
+        let answers_path = AocInput::get_answers_path(&day.input_name());
+        let (passed, message) = verify_day(day, &path, &answers_path, args.record)?;
+        println!("Day {}: {message}", day.number());
+        if !passed {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        bail!("{failed} day(s) failed verification");
+    }
+
+    Ok(())
+}
+
+/// Verifies the answers of a single day, returning whether it passed and the result to print. A
+/// day without recorded answers passes.
+fn verify_day(
+    day: Day,
+    input_path: &Path,
+    answers_path: &Path,
+    record: bool,
+) -> Result<(bool, String)> {
+    let expected = read_answers(answers_path)?;
+    // A day that panics fails, but shouldn't stop the other days from being verified.
+    let result = panic::catch_unwind(|| {
+        let input = day.parse(AocInput::from_file(input_path));
+        [day.run(1, &input), day.run(2, &input)]
+    });
+
+    let actual = match result {
+        Ok(actual) => actual,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            return Ok((false, format!("FAIL (panicked: {message})")));
+        }
+    };
+
+    let Some(expected) = expected else {
+        if record {
+            let [part1, part2] = actual;
+            write_answers(answers_path, &[Some(part1), Some(part2)])?;
+            return Ok((true, "recorded".into()));
+        }
+
+        return Ok((true, "missing answer".into()));
+    };
+
+    let mut errors = Vec::new();
+    let mut missing = Vec::new();
+    for (part, (expected, actual)) in (1..).zip(expected.iter().zip(&actual)) {
+        match expected {
+            Some(expected) if expected != actual => {
+                errors.push(format!("part {part} expected {expected}, got {actual}"))
+            }
+            Some(_) => {}
+            None => missing.push(format!("part {part}")),
+        }
+    }
+
+    Ok(if !errors.is_empty() {
+        (false, format!("FAIL ({})", errors.join("; ")))
+    } else if !missing.is_empty() {
+        (
+            true,
+            format!("pass (missing answer for {})", missing.join(", ")),
+        )
+    } else {
+        (true, "pass".into())
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown error"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Display, fs};
+
+    use aoc::solution::Solution;
+
+    use super::*;
+
+    // Part 1 counts the lines, and part 2 adds them up, panicking if a line isn't a number.
+    struct Stub;
+
+    impl Solution for Stub {
+        type Parsed = Vec<String>;
+
+        fn parse(input: AocInput) -> Self::Parsed {
+            input.into_vec()
+        }
+
+        fn part1(input: &Self::Parsed) -> impl Display {
+            input.len()
+        }
+
+        fn part2(input: &Self::Parsed) -> impl Display {
+            input
+                .iter()
+                .map(|line| line.parse::<u32>().expect("not a number"))
+                .sum::<u32>()
+        }
+    }
+
+    #[test]
+    fn test_verify_panic() {
+        let dir = tempfile::tempdir().unwrap();
+        let input_path = dir.path().join("day1.txt");
+        let answers_path = dir.path().join("answers/day1.txt");
+        fs::write(&input_path, "1\nx\n").unwrap();
+        write_answers(&answers_path, &[Some("2".into()), Some("3".into())]).unwrap();
+
+        let (passed, message) =
+            verify_day(Day::new::<Stub>(1), &input_path, &answers_path, false).unwrap();
+        assert!(!passed);
+        assert!(message.starts_with("FAIL (panicked: not a number: "));
+    }
+
+    #[test]
+    fn test_verify_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let input_path = dir.path().join("day1.txt");
+        let answers_path = dir.path().join("answers/day1.txt");
+        fs::write(&input_path, "1\n2\n").unwrap();
+        let day = Day::new::<Stub>(1);
+
+        let result = verify_day(day, &input_path, &answers_path, false).unwrap();
+        assert_eq!((true, "missing answer".into()), result);
+        assert!(!answers_path.exists());
+
+        let result = verify_day(day, &input_path, &answers_path, true).unwrap();
+        assert_eq!((true, "recorded".into()), result);
+        assert_eq!("2\n3\n", fs::read_to_string(&answers_path).unwrap());
+
+        let result = verify_day(day, &input_path, &answers_path, false).unwrap();
+        assert_eq!((true, "pass".into()), result);
+
+        write_answers(&answers_path, &[Some("2".into()), Some("4".into())]).unwrap();
+        let result = verify_day(day, &input_path, &answers_path, true).unwrap();
+        assert_eq!((false, "FAIL (part 2 expected 4, got 3)".into()), result);
+    }
+}
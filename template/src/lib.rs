@@ -0,0 +1,30 @@
+// https://adventofcode.com/%YEAR%/day/%DAY%
+
+use std::fmt::Display;
+
+use aoc::{input::AocInput, solution::Solution};
+
+pub struct Day%DAY%;
+
+impl Solution for Day%DAY% {
+    type Parsed = Vec<String>;
+
+    fn parse(input: AocInput) -> Self::Parsed {
+        input.into_vec()
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        input.iter().map(|_| 0).sum::<usize>()
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        input.iter().map(|_| 0).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::aoc_tests!(Day%DAY%, part1: [("", 0)], part2: [("", 0)]);
+}